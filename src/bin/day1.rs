@@ -0,0 +1,155 @@
+use advent2025::load_inputs;
+use advent2025::year2025::day1::{Dial, parse_instructions};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::env;
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fs;
+use std::path::PathBuf;
+
+const SIZE: u32 = 600;
+const CENTER: (i32, i32) = (SIZE as i32 / 2, SIZE as i32 / 2);
+const RADIUS: f64 = 240.0;
+
+struct Options {
+	limit: usize,
+	delay: u32,
+	frames: Option<PathBuf>,
+}
+
+struct Frame {
+	label: String,
+	position: i64,
+	nb_stops: usize,
+	nb_passes: i64,
+}
+
+fn read_options_from_args() -> Result<Options, Box<dyn Error>> {
+	let mut options = Options {
+		limit: usize::MAX,
+		delay: 200,
+		frames: None,
+	};
+	let mut args = env::args().skip(1);
+	while let Some(arg) = args.next() {
+		let value = args
+			.next()
+			.ok_or_else(|| format!("Missing value for {arg}"))?;
+		match arg.as_str() {
+			"--limit" => options.limit = value.parse()?,
+			"--delay" => options.delay = value.parse()?,
+			"--frames" => options.frames = Some(value.into()),
+			_ => return Err(format!("Unknown argument: {arg}").into()),
+		}
+	}
+	Ok(options)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let options = read_options_from_args()?;
+	let input = load_inputs(2025, 1)
+		.unwrap()
+		.into_iter()
+		.next()
+		.expect("No input for day 1");
+	let instructions = parse_instructions(input.content.as_bytes()).unwrap();
+	let dial = Dial::default();
+
+	let mut frames = vec![Frame {
+		label: "start".to_string(),
+		position: dial.position(),
+		nb_stops: 0,
+		nb_passes: 0,
+	}];
+	for rotation in dial.rotations(instructions).take(options.limit) {
+		let previous = &frames[frames.len() - 1];
+		frames.push(Frame {
+			label: format!("#{} {}", rotation.index + 1, rotation.instruction),
+			position: rotation.to,
+			nb_stops: previous.nb_stops + usize::from(rotation.to == dial.target()),
+			nb_passes: previous.nb_passes + rotation.nb_crossings,
+		});
+	}
+
+	match &options.frames {
+		Some(directory) => {
+			fs::create_dir_all(directory)?;
+			for (i, frame) in frames.iter().enumerate() {
+				let path = directory.join(format!("day1-{i:05}.png"));
+				let area = BitMapBackend::new(&path, (SIZE, SIZE)).into_drawing_area();
+				draw_frame(&area, &dial, frame)?;
+				area.present()?;
+			}
+		}
+		None => {
+			let area =
+				BitMapBackend::gif("day1.gif", (SIZE, SIZE), options.delay)?.into_drawing_area();
+			for frame in &frames {
+				draw_frame(&area, &dial, frame)?;
+				area.present()?;
+			}
+		}
+	}
+
+	Ok(())
+}
+
+fn point_on_dial(dial: &Dial, position: i64, radius: f64) -> (i32, i32) {
+	let angle = 2.0 * PI * position as f64 / dial.size() as f64;
+	(
+		CENTER.0 + (radius * angle.sin()) as i32,
+		CENTER.1 - (radius * angle.cos()) as i32,
+	)
+}
+
+fn draw_frame(
+	area: &DrawingArea<BitMapBackend, Shift>,
+	dial: &Dial,
+	frame: &Frame,
+) -> Result<(), Box<dyn Error>> {
+	area.fill(&WHITE)?;
+	area.draw(&Circle::new(
+		CENTER,
+		RADIUS as i32,
+		ShapeStyle::from(&BLACK).stroke_width(3),
+	))?;
+
+	for position in 0..dial.size() {
+		let length = if position % 10 == 0 { 20.0 } else { 8.0 };
+		let color = if position == dial.target() {
+			&RED
+		} else {
+			&BLACK
+		};
+		area.draw(&PathElement::new(
+			vec![
+				point_on_dial(dial, position, RADIUS),
+				point_on_dial(dial, position, RADIUS - length),
+			],
+			ShapeStyle::from(color).stroke_width(2),
+		))?;
+	}
+
+	area.draw(&PathElement::new(
+		vec![CENTER, point_on_dial(dial, frame.position, RADIUS - 30.0)],
+		ShapeStyle::from(&BLUE).stroke_width(5),
+	))?;
+	area.draw(&Circle::new(CENTER, 8, ShapeStyle::from(&BLUE).filled()))?;
+
+	let style = ("sans-serif", 20).into_font().color(&BLACK);
+	area.draw_text(&frame.label, &style, (10, 10))?;
+	area.draw_text(&format!("position {}", frame.position), &style, (10, 35))?;
+	area.draw_text(
+		&format!("stops at zero {}", frame.nb_stops),
+		&style,
+		(10, SIZE as i32 - 55),
+	)?;
+	area.draw_text(
+		&format!("passes zero {}", frame.nb_passes),
+		&style,
+		(10, SIZE as i32 - 30),
+	)?;
+
+	Ok(())
+}
@@ -0,0 +1,88 @@
+use advent2025::load_inputs;
+use advent2025::year2025::Radix;
+use advent2025::year2025::day2::{
+	Rule, count_invalid_ids, explain_invalid_ids, normalise_ranges, parse_instruction_ranges,
+	sum_invalid_ids,
+};
+use std::env;
+use std::error::Error;
+use std::ops::RangeInclusive;
+
+struct Options {
+	rule: Rule,
+	radix: Radix,
+	count: bool,
+	explain: bool,
+	filter: RangeInclusive<u64>,
+	limit: usize,
+}
+
+fn read_options_from_args() -> Result<Options, Box<dyn Error>> {
+	let mut options = Options {
+		rule: Rule::at_least(2),
+		radix: Radix::DECIMAL,
+		count: false,
+		explain: false,
+		filter: 0..=u64::MAX,
+		limit: usize::MAX,
+	};
+	let mut range = None;
+	let mut args = env::args().skip(1);
+	while let Some(arg) = args.next() {
+		if let Some(flag) = match arg.as_str() {
+			"--count" => Some(&mut options.count),
+			"--explain" => Some(&mut options.explain),
+			_ => None,
+		} {
+			*flag = true;
+			continue;
+		}
+		let value = args
+			.next()
+			.ok_or_else(|| format!("Missing value for {arg}"))?;
+		match arg.as_str() {
+			"--rule" => options.rule = value.parse::<Rule>().map_err(|error| error.0)?,
+			"--radix" => options.radix = Radix::new(value.parse()?).map_err(|error| error.0)?,
+			"--range" => range = Some(value),
+			"--limit" => options.limit = value.parse()?,
+			_ => return Err(format!("Unknown argument: {arg}").into()),
+		}
+	}
+	if let Some(range) = range {
+		let (start, end) = range
+			.split_once('-')
+			.ok_or_else(|| format!("Invalid range: {range}"))?;
+		let radix = options.radix.get();
+		options.filter = u64::from_str_radix(start, radix)?..=u64::from_str_radix(end, radix)?;
+	}
+	Ok(options)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let options = read_options_from_args()?;
+	for input in load_inputs(2025, 2).unwrap() {
+		let ranges = parse_instruction_ranges(&input.content).unwrap();
+		if options.explain {
+			println!("{}:", input.name);
+			let explanations = explain_invalid_ids(
+				&ranges,
+				options.filter.clone(),
+				&options.rule,
+				options.radix,
+			);
+			for explanation in explanations.take(options.limit) {
+				println!("{explanation}");
+			}
+			continue;
+		}
+		let ranges = normalise_ranges(ranges);
+		if options.count {
+			let count = count_invalid_ids(ranges, &options.rule, options.radix);
+			println!("{}: {count} matching ids", input.name);
+		} else {
+			let sum = sum_invalid_ids(ranges, &options.rule, options.radix);
+			println!("{}: sum of matching ids {sum}", input.name);
+		}
+	}
+	Ok(())
+}
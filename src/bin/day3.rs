@@ -0,0 +1,32 @@
+use advent2025::load_inputs;
+use advent2025::year2025::Radix;
+use advent2025::year2025::day3::{JoltageTable, parse_banks_with_radix};
+use std::env;
+use std::error::Error;
+use std::io;
+
+fn read_radix_from_args() -> Result<Radix, Box<dyn Error>> {
+	let args: Vec<_> = env::args().skip(1).collect();
+	match args.as_slice() {
+		[] => Ok(Radix::DECIMAL),
+		[option, radix] if option == "--radix" => {
+			Ok(Radix::new(radix.parse()?).map_err(|error| error.0)?)
+		}
+		_ => Err("Usage: day3 [--radix N]".into()),
+	}
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let radix = read_radix_from_args()?;
+	let input = load_inputs(2025, 3)
+		.unwrap()
+		.into_iter()
+		.next()
+		.expect("No input for day 3");
+	let banks = parse_banks_with_radix(input.content.as_bytes(), radix).unwrap();
+	JoltageTable::new(&banks)
+		.unwrap()
+		.write_csv(&mut io::stdout().lock())
+		.unwrap();
+	Ok(())
+}
@@ -1,4 +1,5 @@
-use advent2025::day9::{INPUT, Tile, parse_tiles};
+use advent2025::load_inputs;
+use advent2025::year2025::day9::{Tile, parse_tiles};
 use plotters::prelude::*;
 use std::env;
 use std::error::Error;
@@ -25,7 +26,12 @@ fn read_rectangle_from_args() -> Option<(Tile, Tile)> {
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
-	let tiles = parse_tiles(INPUT).unwrap();
+	let input = load_inputs(2025, 9)
+		.unwrap()
+		.into_iter()
+		.next()
+		.expect("No input for day 9");
+	let tiles = parse_tiles(input.content.as_bytes()).unwrap();
 	let (max_x, max_y) = tiles.iter().fold((0, 0), |(max_x, max_y), &Tile { x, y }| {
 		(max_x.max(x), max_y.max(y))
 	});
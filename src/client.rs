@@ -0,0 +1,339 @@
+use regex::Regex;
+use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs};
+use ureq::Agent;
+
+use crate::input::input_directory;
+use crate::{Error, Result, error};
+
+#[cfg(test)]
+mod mock_server;
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/alightgoesout/advent2025";
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Verdict {
+	Correct,
+	Incorrect,
+	TooRecent(Duration),
+	AlreadySolved,
+}
+
+pub struct Client {
+	base_url: String,
+	session: String,
+	account: String,
+	directory: PathBuf,
+	agent: Agent,
+}
+
+impl Client {
+	pub fn new(base_url: &str, session: &str, account: &str, directory: PathBuf) -> Self {
+		Self {
+			base_url: base_url.trim_end_matches('/').to_string(),
+			session: session.to_string(),
+			account: account.to_string(),
+			directory,
+			agent: Agent::new_with_defaults(),
+		}
+	}
+
+	pub fn from_env() -> Result<Self> {
+		let session = env::var("AOC_SESSION").map_err(|_| error!("AOC_SESSION is not set"))?;
+		let account = env::var("AOC_ACCOUNT").unwrap_or_else(|_| "default".to_string());
+		let base_url = env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_URL.to_string());
+		Ok(Self::new(&base_url, &session, &account, input_directory()))
+	}
+
+	pub fn download_input(&self, year: u16, day: usize) -> Result<String> {
+		let path = self.input_path(year, day);
+		if path.is_file() {
+			return Ok(fs::read_to_string(path)?);
+		}
+		let input = self
+			.agent
+			.get(format!("{}/{year}/day/{day}/input", self.base_url))
+			.header("Cookie", format!("session={}", self.session))
+			.header("User-Agent", USER_AGENT)
+			.call()?
+			.body_mut()
+			.read_to_string()?;
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		fs::write(path, &input)?;
+		Ok(input)
+	}
+
+	pub fn submit_answer(&self, year: u16, day: usize, part: u8, answer: &str) -> Result<Verdict> {
+		let mut submissions = self.load_submissions(year, day)?;
+		if let Some(wait_until) = submissions.wait_until
+			&& let Ok(wait) = wait_until.duration_since(SystemTime::now())
+		{
+			return Ok(Verdict::TooRecent(wait));
+		}
+		if let Some(correct) = submissions.correct(part) {
+			return if correct == answer {
+				Ok(Verdict::Correct)
+			} else {
+				Err(error!("Part {part} was already solved with {correct}"))
+			};
+		}
+		if submissions.is_incorrect(part, answer) {
+			return Err(error!(
+				"Answer {answer} for part {part} was already rejected"
+			));
+		}
+
+		let response = self
+			.agent
+			.post(format!("{}/{year}/day/{day}/answer", self.base_url))
+			.header("Cookie", format!("session={}", self.session))
+			.header("User-Agent", USER_AGENT)
+			.send_form([("level", part.to_string()), ("answer", answer.to_string())])?
+			.body_mut()
+			.read_to_string()?;
+		let verdict = parse_verdict(&response)?;
+
+		match verdict {
+			Verdict::Correct | Verdict::Incorrect => {
+				submissions
+					.answers
+					.push((part, verdict, answer.to_string()))
+			}
+			Verdict::TooRecent(wait) => submissions.wait_until = Some(SystemTime::now() + wait),
+			Verdict::AlreadySolved => {}
+		}
+		self.save_submissions(year, day, &submissions)?;
+		Ok(verdict)
+	}
+
+	fn input_path(&self, year: u16, day: usize) -> PathBuf {
+		self.directory
+			.join(year.to_string())
+			.join(format!("day{day}"))
+			.join(&self.account)
+	}
+
+	fn submissions_path(&self, year: u16, day: usize) -> PathBuf {
+		self.directory
+			.join(year.to_string())
+			.join("answers")
+			.join(&self.account)
+			.join(format!("day{day}"))
+	}
+
+	fn load_submissions(&self, year: u16, day: usize) -> Result<Submissions> {
+		let path = self.submissions_path(year, day);
+		if path.is_file() {
+			fs::read_to_string(path)?.parse()
+		} else {
+			Ok(Submissions::default())
+		}
+	}
+
+	fn save_submissions(&self, year: u16, day: usize, submissions: &Submissions) -> Result<()> {
+		let path = self.submissions_path(year, day);
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		fs::write(path, submissions.to_string())?;
+		Ok(())
+	}
+}
+
+static WAIT: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap());
+
+fn parse_verdict(response: &str) -> Result<Verdict> {
+	if response.contains("That's the right answer") {
+		Ok(Verdict::Correct)
+	} else if response.contains("That's not the right answer") {
+		Ok(Verdict::Incorrect)
+	} else if response.contains("You gave an answer too recently") {
+		let wait = match WAIT.captures(response) {
+			Some(captures) => {
+				let minutes: u64 = captures.get(1).map_or("0", |m| m.as_str()).parse()?;
+				let seconds: u64 = captures[2].parse()?;
+				Duration::from_secs(minutes * 60 + seconds)
+			}
+			None => Duration::ZERO,
+		};
+		Ok(Verdict::TooRecent(wait))
+	} else if response.contains("You don't seem to be solving the right level") {
+		Ok(Verdict::AlreadySolved)
+	} else {
+		Err(error!("Unexpected answer response: {response}"))
+	}
+}
+
+#[derive(Debug, Default)]
+struct Submissions {
+	wait_until: Option<SystemTime>,
+	answers: Vec<(u8, Verdict, String)>,
+}
+
+impl Submissions {
+	fn correct(&self, part: u8) -> Option<&str> {
+		self.answers
+			.iter()
+			.find(|(p, verdict, _)| *p == part && *verdict == Verdict::Correct)
+			.map(|(_, _, answer)| answer.as_str())
+	}
+
+	fn is_incorrect(&self, part: u8, answer: &str) -> bool {
+		self.answers
+			.iter()
+			.any(|(p, verdict, a)| *p == part && *verdict == Verdict::Incorrect && a == answer)
+	}
+}
+
+impl FromStr for Submissions {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let mut submissions = Submissions::default();
+		for line in s.lines() {
+			match line.split_once(' ') {
+				Some(("wait", timestamp)) => {
+					submissions.wait_until =
+						Some(UNIX_EPOCH + Duration::from_secs(timestamp.parse()?))
+				}
+				Some((part, rest)) => {
+					let (verdict, answer) = rest
+						.split_once(' ')
+						.ok_or_else(|| error!("Invalid submission: {line}"))?;
+					let verdict = match verdict {
+						"correct" => Verdict::Correct,
+						"incorrect" => Verdict::Incorrect,
+						_ => return Err(error!("Invalid verdict: {verdict}")),
+					};
+					submissions
+						.answers
+						.push((part.parse()?, verdict, answer.to_string()));
+				}
+				None => return Err(error!("Invalid submission: {line}")),
+			}
+		}
+		Ok(submissions)
+	}
+}
+
+impl Display for Submissions {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		if let Some(wait_until) = self.wait_until {
+			let timestamp = wait_until
+				.duration_since(UNIX_EPOCH)
+				.unwrap_or_default()
+				.as_secs();
+			writeln!(f, "wait {timestamp}")?;
+		}
+		for (part, verdict, answer) in &self.answers {
+			let verdict = if *verdict == Verdict::Correct {
+				"correct"
+			} else {
+				"incorrect"
+			};
+			writeln!(f, "{part} {verdict} {answer}")?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::mock_server::MockServer;
+	use super::*;
+
+	fn client(server: &MockServer, name: &str) -> Client {
+		let directory = env::temp_dir().join(format!("advent2025-{}-{name}", std::process::id()));
+		let _ = fs::remove_dir_all(&directory);
+		Client::new(&server.url(), "secret", "alice", directory)
+	}
+
+	#[test]
+	fn download_input_should_send_session_and_cache_input() {
+		let server = MockServer::start(|_| "1-2,3-4\n".to_string());
+		let client = client(&server, "download");
+
+		assert_eq!(client.download_input(2025, 2).unwrap(), "1-2,3-4\n");
+		assert_eq!(client.download_input(2025, 2).unwrap(), "1-2,3-4\n");
+
+		let requests = server.requests();
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].method, "GET");
+		assert_eq!(requests[0].path, "/2025/day/2/input");
+		assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+		assert_eq!(
+			fs::read_to_string(client.directory.join("2025/day2/alice")).unwrap(),
+			"1-2,3-4\n"
+		);
+	}
+
+	#[test]
+	fn submit_answer_should_post_level_and_answer() {
+		let server = MockServer::start(|_| {
+			"<p>That's the right answer! You are one gold star closer.</p>".to_string()
+		});
+		let client = client(&server, "correct");
+
+		assert_eq!(
+			client.submit_answer(2025, 1, 2, "6").unwrap(),
+			Verdict::Correct
+		);
+		assert_eq!(
+			client.submit_answer(2025, 1, 2, "6").unwrap(),
+			Verdict::Correct
+		);
+
+		let requests = server.requests();
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].method, "POST");
+		assert_eq!(requests[0].path, "/2025/day/1/answer");
+		assert_eq!(requests[0].body, "level=2&answer=6");
+	}
+
+	#[test]
+	fn submit_answer_should_refuse_to_resubmit_known_wrong_answer() {
+		let server = MockServer::start(|_| "<p>That's not the right answer.</p>".to_string());
+		let client = client(&server, "incorrect");
+
+		assert_eq!(
+			client.submit_answer(2025, 1, 1, "42").unwrap(),
+			Verdict::Incorrect
+		);
+		assert!(client.submit_answer(2025, 1, 1, "42").is_err());
+		assert_eq!(server.requests().len(), 1);
+	}
+
+	#[test]
+	fn submit_answer_should_wait_after_answering_too_recently() {
+		let server = MockServer::start(|_| {
+			"<p>You gave an answer too recently. You have 1m 5s left to wait.</p>".to_string()
+		});
+		let client = client(&server, "too-recent");
+
+		assert_eq!(
+			client.submit_answer(2025, 1, 1, "42").unwrap(),
+			Verdict::TooRecent(Duration::from_secs(65))
+		);
+		assert!(matches!(
+			client.submit_answer(2025, 1, 1, "43").unwrap(),
+			Verdict::TooRecent(_)
+		));
+		assert_eq!(server.requests().len(), 1);
+	}
+
+	#[test]
+	fn parse_verdict_should_parse_wait_time_in_seconds() {
+		assert_eq!(
+			parse_verdict("You gave an answer too recently. You have 37s left to wait.").unwrap(),
+			Verdict::TooRecent(Duration::from_secs(37))
+		);
+	}
+}
@@ -11,6 +11,12 @@ impl From<std::io::Error> for Error {
 	}
 }
 
+impl From<ureq::Error> for Error {
+	fn from(value: ureq::Error) -> Self {
+		Self(format!("HTTP error: {value}"))
+	}
+}
+
 impl From<ParseIntError> for Error {
 	fn from(value: ParseIntError) -> Self {
 		Self(format!("Error while parsing integer: {value}"))
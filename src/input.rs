@@ -1,9 +1,44 @@
+use std::fs;
 use std::io::{BufRead, BufReader, Read};
-use std::iter::MapWhile;
+use std::iter::{Map, MapWhile};
 use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use crate::Error;
+use crate::{Error, error};
+
+pub struct Input {
+	pub name: String,
+	pub content: String,
+}
+
+pub fn input_directory() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+}
+
+pub fn load_inputs(year: u16, day: usize) -> Result<Vec<Input>, Error> {
+	let directory = input_directory()
+		.join(year.to_string())
+		.join(format!("day{day}"));
+	let mut paths = fs::read_dir(&directory)
+		.map_err(|e| error!("Cannot read inputs from {}: {e}", directory.display()))?
+		.map(|entry| entry.map(|entry| entry.path()))
+		.collect::<Result<Vec<_>, _>>()?;
+	paths.retain(|path| path.is_file());
+	paths.sort();
+	paths
+		.into_iter()
+		.map(|path| {
+			Ok(Input {
+				name: path
+					.file_name()
+					.map(|name| name.to_string_lossy().into_owned())
+					.unwrap_or_default(),
+				content: fs::read_to_string(&path)?,
+			})
+		})
+		.collect()
+}
 
 pub struct Parse<I, T>(I, PhantomData<T>);
 
@@ -35,8 +70,11 @@ impl<I: Iterator> ParseExt<I> for I {
 
 pub trait ReadLines {
 	type Iterator: Iterator<Item = String>;
+	type TryIterator: Iterator<Item = Result<String, Error>>;
 
 	fn read_lines(self) -> Self::Iterator;
+
+	fn try_read_lines(self) -> Self::TryIterator;
 }
 
 impl<R: Read> ReadLines for R {
@@ -44,8 +82,37 @@ impl<R: Read> ReadLines for R {
 		std::io::Lines<BufReader<R>>,
 		fn(Result<String, std::io::Error>) -> Option<String>,
 	>;
+	type TryIterator = Map<
+		std::io::Lines<BufReader<R>>,
+		fn(Result<String, std::io::Error>) -> Result<String, Error>,
+	>;
 
 	fn read_lines(self) -> Self::Iterator {
 		BufReader::new(self).lines().map_while(Result::ok)
 	}
+
+	fn try_read_lines(self) -> Self::TryIterator {
+		BufReader::new(self)
+			.lines()
+			.map(|line| line.map_err(Error::from))
+	}
+}
+
+pub trait ReadSplit {
+	type Iterator: Iterator<Item = Result<String, Error>>;
+
+	fn read_split(self, separator: u8) -> Self::Iterator;
+}
+
+impl<R: Read> ReadSplit for R {
+	type Iterator = Map<
+		std::io::Split<BufReader<R>>,
+		fn(Result<Vec<u8>, std::io::Error>) -> Result<String, Error>,
+	>;
+
+	fn read_split(self, separator: u8) -> Self::Iterator {
+		BufReader::new(self).split(separator).map(|item| {
+			String::from_utf8(item?).map_err(|e| error!("Input is not valid UTF-8: {e}"))
+		})
+	}
 }
@@ -1,21 +1,119 @@
 use std::env;
+use std::io::stdout;
+use std::path::PathBuf;
 
-use advent2025::solutions;
+use advent2025::client::Client;
+use advent2025::scaffold::new_day;
+use advent2025::{load_inputs, print_answers, solutions};
 
-fn read_day_from_args() -> Option<usize> {
-	env::args()
-		.nth(1)
+fn read_positional_args() -> Vec<String> {
+	let mut args = Vec::new();
+	let mut iter = env::args().skip(1);
+	while let Some(arg) = iter.next() {
+		if ["--year", "--stream"].contains(&arg.as_str()) {
+			iter.next();
+		} else if !arg.starts_with("--") {
+			args.push(arg);
+		}
+	}
+	args
+}
+
+fn split_command(args: &[String]) -> (Option<&str>, &[String]) {
+	match args.split_first() {
+		Some((command, args)) if ["download", "submit", "new"].contains(&command.as_str()) => {
+			(Some(command), args)
+		}
+		_ => (None, args),
+	}
+}
+
+fn read_option_from_args(name: &str) -> Option<String> {
+	env::args().skip_while(|arg| arg != name).nth(1)
+}
+
+fn read_year_from_args() -> Option<u16> {
+	read_option_from_args("--year")
 		.map(|arg| arg.parse())
 		.transpose()
-		.expect("Invalid day")
+		.expect("Invalid year")
+}
+
+fn read_stream_path_from_args() -> Option<PathBuf> {
+	read_option_from_args("--stream").map(PathBuf::from)
+}
+
+fn read_trace_flag_from_args() -> bool {
+	env::args().any(|arg| arg == "--trace")
 }
 
 fn main() {
 	let solutions = solutions();
-	let day = read_day_from_args().unwrap_or(solutions.len());
-	if let Some(solution) = solutions.get(day - 1) {
-		solution.execute(day as u8).unwrap()
-	} else {
-		println!("Unknown day {day}")
+	let Some(&(latest_year, _)) = solutions.keys().last() else {
+		println!("No solutions");
+		return;
+	};
+	let year = read_year_from_args().unwrap_or(latest_year);
+	let args = read_positional_args();
+	let (command, args) = split_command(&args);
+	let day = args
+		.first()
+		.map(|arg| arg.parse().expect("Invalid day"))
+		.or_else(|| {
+			solutions
+				.range((year, 0)..=(year, usize::MAX))
+				.last()
+				.map(|(&(_, day), _)| day)
+		})
+		.unwrap_or_default();
+
+	match command {
+		Some("new") => {
+			let path = new_day(year, day).unwrap();
+			println!("Created {}", path.display());
+		}
+		Some("download") => {
+			let client = Client::from_env().unwrap();
+			client.download_input(year, day).unwrap();
+			println!("Downloaded input of {year} day {day}");
+		}
+		Some("submit") => {
+			let (Some(part), Some(answer)) = (args.get(1), args.get(2)) else {
+				println!("Usage: submit <day> <part> <answer>");
+				return;
+			};
+			let client = Client::from_env().unwrap();
+			let verdict = client
+				.submit_answer(year, day, part.parse().expect("Invalid part"), answer)
+				.unwrap();
+			println!("{verdict:?}");
+		}
+		_ => run(year, day),
+	}
+}
+
+fn run(year: u16, day: usize) {
+	let Some(solution) = solutions().get(&(year, day)).copied() else {
+		println!("Unknown day {day} of {year}");
+		return;
+	};
+	if read_trace_flag_from_args() {
+		for input in load_inputs(year, day).unwrap() {
+			println!("{}:", input.name);
+			solution(&input.content).trace(&mut stdout()).unwrap();
+		}
+		return;
 	}
+	let answers = match read_stream_path_from_args() {
+		Some(path) => vec![(
+			path.display().to_string(),
+			solution("").execute_streaming(&path),
+		)],
+		None => load_inputs(year, day)
+			.unwrap()
+			.into_iter()
+			.map(|input| (input.name, solution(&input.content).execute()))
+			.collect(),
+	};
+	print_answers(year, day, &answers);
 }
@@ -0,0 +1,254 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input::input_directory;
+use crate::{Result, error};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs.tpl");
+const EMPTY_REGISTRY: &str = "\
+use crate::SolutionFactory;
+
+pub fn solutions() -> Vec<SolutionFactory> {
+	vec![
+	]
+}
+";
+
+pub fn new_day(year: u16, day: usize) -> Result<PathBuf> {
+	let module = scaffold_day(
+		&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+		year,
+		day,
+	)?;
+	fs::create_dir_all(
+		input_directory()
+			.join(year.to_string())
+			.join(format!("day{day}")),
+	)?;
+	Ok(module)
+}
+
+fn scaffold_day(source_directory: &Path, year: u16, day: usize) -> Result<PathBuf> {
+	let year_directory = source_directory.join(format!("year{year}"));
+	let registry_path = year_directory.join("mod.rs");
+	let module_path = year_directory.join(format!("day{day}.rs"));
+
+	if module_path.exists() {
+		return Err(error!("Day {day} of {year} already exists"));
+	}
+	let (registry, library) = if year_directory.exists() {
+		let registry = fs::read_to_string(&registry_path)
+			.map_err(|e| error!("Cannot read {}: {e}", registry_path.display()))?;
+		(registry, None)
+	} else {
+		let library_path = source_directory.join("lib.rs");
+		let library = fs::read_to_string(&library_path)
+			.map_err(|e| error!("Cannot read {}: {e}", library_path.display()))?;
+		let library = register_year(&library, year)?;
+		(EMPTY_REGISTRY.to_string(), Some((library_path, library)))
+	};
+	let registry = register_day(&registry, day)?;
+
+	fs::create_dir_all(&year_directory)?;
+	fs::write(&module_path, TEMPLATE.replace("{{day}}", &day.to_string()))?;
+	fs::write(&registry_path, registry)?;
+	if let Some((library_path, library)) = library {
+		fs::write(library_path, library)?;
+	}
+	Ok(module_path)
+}
+
+fn register_year(library: &str, year: u16) -> Result<String> {
+	let mut lines: Vec<_> = library.lines().map(str::to_string).collect();
+	let last_module = lines
+		.iter()
+		.rposition(|line| line.starts_with("pub mod year"))
+		.ok_or_else(|| error!("Cannot find the year modules"))?;
+	let registry = lines
+		.iter_mut()
+		.find(|line| line.trim_start().starts_with("[(") && line.ends_with("solutions())]"))
+		.ok_or_else(|| error!("Cannot find the list of years"))?;
+	registry.pop();
+	registry.push_str(&format!(", ({year}, year{year}::solutions())]"));
+	lines.insert(last_module + 1, format!("pub mod year{year};"));
+	Ok(lines.join("\n") + "\n")
+}
+
+fn register_day(registry: &str, day: usize) -> Result<String> {
+	let lines: Vec<_> = registry.lines().collect();
+	let factory_start = lines
+		.iter()
+		.position(|line| line.trim() == "vec![")
+		.ok_or_else(|| error!("Cannot find the solutions list"))?;
+	let factory_end = factory_start
+		+ lines[factory_start..]
+			.iter()
+			.position(|line| line.trim() == "]")
+			.ok_or_else(|| error!("Cannot find the end of the solutions list"))?;
+	let nb_days = factory_end - factory_start - 1;
+	if day != nb_days + 1 {
+		return Err(error!("Day {day} must follow day {nb_days}"));
+	}
+
+	let module_declarations: Vec<_> = lines
+		.iter()
+		.take_while(|line| line.starts_with("mod ") || line.starts_with("pub mod "))
+		.copied()
+		.collect();
+	let module = format!("mod day{day};");
+	let mut declarations = module_declarations.clone();
+	declarations.push(&module);
+	declarations.sort_by_key(|declaration| {
+		declaration
+			.trim_start_matches("pub ")
+			.trim_start_matches("mod ")
+			.trim_end_matches(';')
+			.to_string()
+	});
+	if module_declarations.is_empty() {
+		declarations.push("");
+	}
+
+	let factory = format!("\t\t|input| Box::new(day{day}::Day{day}::new(input)),");
+	let mut result = declarations;
+	result.extend(&lines[module_declarations.len()..factory_end]);
+	result.push(&factory);
+	result.extend(&lines[factory_end..]);
+	Ok(result.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::env;
+
+	const REGISTRY: &str = "\
+mod day1;
+mod day10;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+pub mod day9;
+
+use crate::SolutionFactory;
+
+pub fn solutions() -> Vec<SolutionFactory> {
+	vec![
+		|input| Box::new(day1::Day1::new(input)),
+		|input| Box::new(day2::Day2::new(input)),
+		|input| Box::new(day3::Day3::new(input)),
+		|input| Box::new(day4::Day4::new(input)),
+		|input| Box::new(day5::Day5::new(input)),
+		|input| Box::new(day6::Day6::new(input)),
+		|input| Box::new(day7::Day7::new(input)),
+		|input| Box::new(day8::Day8::new(input)),
+		|input| Box::new(day9::Day9::new(input)),
+		|input| Box::new(day10::Day10::new(input)),
+	]
+}
+";
+
+	#[test]
+	fn register_day_should_add_module_and_factory() {
+		let registry = register_day(REGISTRY, 11).unwrap();
+		assert_eq!(
+			registry,
+			REGISTRY
+				.replace("mod day10;\n", "mod day10;\nmod day11;\n")
+				.replace(
+					"day10::Day10::new(input)),\n",
+					"day10::Day10::new(input)),\n\t\t|input| Box::new(day11::Day11::new(input)),\n"
+				)
+		);
+	}
+
+	#[test]
+	fn register_day_should_refuse_a_gap_between_days() {
+		assert!(register_day(REGISTRY, 12).is_err());
+	}
+
+	#[test]
+	fn scaffold_day_should_refuse_to_overwrite_an_existing_day() {
+		let source_directory =
+			env::temp_dir().join(format!("advent2025-{}-scaffold", std::process::id()));
+		let year_directory = source_directory.join("year2025");
+		let _ = fs::remove_dir_all(&source_directory);
+		fs::create_dir_all(&year_directory).unwrap();
+		fs::write(year_directory.join("mod.rs"), REGISTRY).unwrap();
+
+		let module_path = scaffold_day(&source_directory, 2025, 11).unwrap();
+		let module = fs::read_to_string(&module_path).unwrap();
+
+		assert!(module.contains("pub struct Day11<'a> {"));
+		assert!(
+			fs::read_to_string(year_directory.join("mod.rs"))
+				.unwrap()
+				.contains("mod day11;")
+		);
+		assert!(scaffold_day(&source_directory, 2025, 11).is_err());
+		assert_eq!(fs::read_to_string(&module_path).unwrap(), module);
+	}
+
+	#[test]
+	fn register_year_should_add_module_and_solutions() {
+		let library = "\
+mod solution;
+pub mod year2025;
+
+pub fn solutions() -> BTreeMap<(u16, usize), SolutionFactory> {
+	[(2025, year2025::solutions())]
+		.into_iter()
+";
+		assert_eq!(
+			register_year(library, 2026).unwrap(),
+			library
+				.replace(
+					"pub mod year2025;\n",
+					"pub mod year2025;\npub mod year2026;\n"
+				)
+				.replace(
+					"[(2025, year2025::solutions())]",
+					"[(2025, year2025::solutions()), (2026, year2026::solutions())]"
+				)
+		);
+	}
+
+	#[test]
+	fn scaffold_day_should_create_a_new_year() {
+		let source_directory =
+			env::temp_dir().join(format!("advent2025-{}-scaffold-year", std::process::id()));
+		let _ = fs::remove_dir_all(&source_directory);
+		fs::create_dir_all(&source_directory).unwrap();
+		fs::write(
+			source_directory.join("lib.rs"),
+			"pub mod year2025;\n\n\t[(2025, year2025::solutions())]\n",
+		)
+		.unwrap();
+
+		assert!(scaffold_day(&source_directory, 2026, 2).is_err());
+		scaffold_day(&source_directory, 2026, 1).unwrap();
+
+		assert_eq!(
+			fs::read_to_string(source_directory.join("year2026/mod.rs")).unwrap(),
+			"\
+mod day1;
+
+use crate::SolutionFactory;
+
+pub fn solutions() -> Vec<SolutionFactory> {
+	vec![
+		|input| Box::new(day1::Day1::new(input)),
+	]
+}
+"
+		);
+		assert_eq!(
+			fs::read_to_string(source_directory.join("lib.rs")).unwrap(),
+			"pub mod year2025;\npub mod year2026;\n\n\t[(2025, year2025::solutions()), (2026, year2026::solutions())]\n"
+		);
+	}
+}
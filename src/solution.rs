@@ -1,21 +1,108 @@
-use std::time::Instant;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::Path;
+use std::time::{Duration, Instant};
 
-use crate::Result;
+use crate::{Result, error};
 
 pub trait Solution {
 	fn part_one(&self) -> Result<String>;
 	fn part_two(&self) -> Result<String>;
 
-	fn execute(&self, day: u8) -> Result<()> {
+	fn stream_part_one(&self, _input: &mut dyn Read) -> Result<String> {
+		Err(error!("Part 1 does not support streaming"))
+	}
+
+	fn stream_part_two(&self, _input: &mut dyn Read) -> Result<String> {
+		Err(error!("Part 2 does not support streaming"))
+	}
+
+	fn trace(&self, _output: &mut dyn Write) -> Result<()> {
+		Err(error!("No trace available"))
+	}
+
+	fn execute(&self) -> [Answer; 2] {
+		[
+			Answer::compute(|| self.part_one()),
+			Answer::compute(|| self.part_two()),
+		]
+	}
+
+	fn execute_streaming(&self, path: &Path) -> [Answer; 2] {
+		[
+			Answer::compute(|| self.stream_part_one(&mut File::open(path)?)),
+			Answer::compute(|| self.stream_part_two(&mut File::open(path)?)),
+		]
+	}
+}
+
+pub struct Answer {
+	pub result: Result<String>,
+	pub duration: Duration,
+}
+
+impl Answer {
+	fn compute(part: impl FnOnce() -> Result<String>) -> Self {
 		let start = Instant::now();
-		println!("{day}:1 — {}", self.part_one()?);
-		let part1_duration = start.elapsed();
-		println!("Part 1 in {}ms", part1_duration.as_millis());
-		println!("{day}:2 — {}", self.part_two()?);
-		let part2_duration = start.elapsed() - part1_duration;
-		println!("Part 2 in {}ms", part2_duration.as_millis());
-		let total_duration = start.elapsed();
-		println!("Done in {}ms", total_duration.as_millis());
-		Ok(())
+		let result = catch_unwind(AssertUnwindSafe(part)).unwrap_or_else(|panic| {
+			let message = panic
+				.downcast_ref::<&str>()
+				.map(|message| message.to_string())
+				.or_else(|| panic.downcast_ref::<String>().cloned())
+				.unwrap_or_default();
+			Err(error!("Panicked: {message}"))
+		});
+		Self {
+			result,
+			duration: start.elapsed(),
+		}
+	}
+}
+
+pub fn print_answers(year: u16, day: usize, answers: &[(String, [Answer; 2])]) {
+	let mut rows = vec![vec![
+		format!("{year} day {day}"),
+		"Part 1".to_string(),
+		"Time".to_string(),
+		"Part 2".to_string(),
+		"Time".to_string(),
+	]];
+	for (input, [part_one, part_two]) in answers {
+		rows.push(vec![
+			input.clone(),
+			format_result(&part_one.result),
+			format!("{:.2?}", part_one.duration),
+			format_result(&part_two.result),
+			format!("{:.2?}", part_two.duration),
+		]);
+	}
+
+	let widths: Vec<_> = (0..rows[0].len())
+		.map(|column| {
+			rows.iter()
+				.map(|row| row[column].chars().count())
+				.max()
+				.unwrap_or_default()
+		})
+		.collect();
+	for (i, row) in rows.iter().enumerate() {
+		let cells: Vec<_> = row
+			.iter()
+			.zip(&widths)
+			.map(|(cell, width)| format!("{cell:width$}"))
+			.collect();
+		println!("{}", cells.join(" | ").trim_end());
+		if i == 0 {
+			let separators: Vec<_> = widths.iter().map(|width| "-".repeat(*width)).collect();
+			println!("{}", separators.join("-+-"));
+		}
+	}
+}
+
+fn format_result(result: &Result<String>) -> String {
+	match result {
+		Ok(answer) => answer.clone(),
+		Err(error) => format!("Error: {}", error.0),
 	}
 }
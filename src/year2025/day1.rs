@@ -0,0 +1,802 @@
+use itertools::process_results;
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::io::{Read, Write};
+use std::iter;
+use std::str::FromStr;
+
+use crate::input::ReadLines;
+use crate::{Error, Result, Solution, error};
+use program::parse_program;
+
+mod program;
+
+pub struct Day1<'a> {
+	input: &'a str,
+	instructions: OnceCell<Vec<(usize, Instruction)>>,
+}
+
+impl<'a> Day1<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			instructions: OnceCell::new(),
+		}
+	}
+
+	fn numbered_instructions(&self) -> Result<&Vec<(usize, Instruction)>> {
+		self.instructions
+			.get_or_try_init(|| parse_numbered_instructions(self.input.as_bytes()))
+	}
+
+	fn instructions(&self) -> Result<impl Iterator<Item = Instruction>> {
+		Ok(self
+			.numbered_instructions()?
+			.iter()
+			.map(|&(_, instruction)| instruction))
+	}
+}
+
+impl Solution for Day1<'_> {
+	fn part_one(&self) -> Result<String> {
+		let nb_stops_at_zero =
+			execute_instructions_and_count_nb_stops_at_zero(self.instructions()?);
+		Ok(format!(
+			"Number of times the dial stops at zero: {nb_stops_at_zero}"
+		))
+	}
+
+	fn part_two(&self) -> Result<String> {
+		let nb_zero =
+			execute_instructions_and_count_nb_zero(self.numbered_instructions()?.iter().copied())?;
+		Ok(format!("Number of times the dial passes zero: {nb_zero}"))
+	}
+
+	fn stream_part_one(&self, input: &mut dyn Read) -> Result<String> {
+		let nb_stops_at_zero = process_results(stream_instructions(input), |instructions| {
+			execute_instructions_and_count_nb_stops_at_zero(
+				instructions.map(|(_, instruction)| instruction),
+			)
+		})?;
+		Ok(format!(
+			"Number of times the dial stops at zero: {nb_stops_at_zero}"
+		))
+	}
+
+	fn stream_part_two(&self, input: &mut dyn Read) -> Result<String> {
+		let nb_zero = process_results(stream_instructions(input), |instructions| {
+			execute_instructions_and_count_nb_zero(instructions)
+		})??;
+		Ok(format!("Number of times the dial passes zero: {nb_zero}"))
+	}
+
+	fn trace(&self, output: &mut dyn Write) -> Result<()> {
+		for event in Dial::default().events(self.instructions()?) {
+			writeln!(output, "{event}")?;
+		}
+		Ok(())
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Direction {
+	Left,
+	Right,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Instruction {
+	pub direction: Direction,
+	pub amount: i64,
+}
+
+impl Instruction {
+	pub fn new(direction: Direction, amount: i64) -> Self {
+		Self { direction, amount }
+	}
+}
+
+impl Display for Instruction {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.direction {
+			Direction::Left => write!(f, "L{}", self.amount),
+			Direction::Right => write!(f, "R{}", self.amount),
+		}
+	}
+}
+
+impl FromStr for Instruction {
+	type Err = Error;
+
+	fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+		let (direction, amount) = if let Some(amount) = s.strip_prefix('L') {
+			(Direction::Left, amount)
+		} else if let Some(amount) = s.strip_prefix('R') {
+			(Direction::Right, amount)
+		} else {
+			return Err(error!("Invalid direction: {s}"));
+		};
+		if !amount.starts_with(|c: char| c.is_ascii_digit()) {
+			return Err(error!("Invalid amount: {amount}"));
+		}
+		let amount = i64::try_from(amount.parse::<u64>()?)
+			.map_err(|_| error!("Amount is too large: {amount}"))?;
+		Ok(Self::new(direction, amount))
+	}
+}
+
+pub fn parse_instructions(instructions: &[u8]) -> Result<Vec<Instruction>> {
+	Ok(parse_numbered_instructions(instructions)?
+		.into_iter()
+		.map(|(_, instruction)| instruction)
+		.collect())
+}
+
+fn parse_numbered_instructions(instructions: &[u8]) -> Result<Vec<(usize, Instruction)>> {
+	let program = std::str::from_utf8(instructions)
+		.map_err(|e| error!("Instructions are not valid UTF-8: {e}"))?;
+	parse_program(program)
+}
+
+/// Streams instructions line by line. Comments, blank lines and several instructions on a
+/// line are accepted, but repetitions and macros need the whole program and are rejected.
+fn stream_instructions(input: impl Read) -> impl Iterator<Item = Result<(usize, Instruction)>> {
+	input.try_read_lines().enumerate().flat_map(|(i, line)| {
+		let line = match line {
+			Ok(line) => line,
+			Err(error) => return vec![Err(error)],
+		};
+		let code = line.split('#').next().unwrap_or_default();
+		code.split_whitespace()
+			.map(|word| {
+				if word == "def" || word.contains(['x', '{', '}', '@']) {
+					return Err(error!(
+						"Repetitions and macros are not supported when streaming, on line {}",
+						i + 1
+					));
+				}
+				let instruction = word
+					.parse::<Instruction>()
+					.map_err(|e| error!("Invalid instruction on line {}: {}", i + 1, e.0))?;
+				Ok((i + 1, instruction))
+			})
+			.collect::<Vec<_>>()
+	})
+}
+
+fn execute_instructions_and_count_nb_stops_at_zero(
+	instructions: impl IntoIterator<Item = Instruction>,
+) -> usize {
+	Dial::default().count_stops_at_target(instructions)
+}
+
+fn execute_instructions_and_count_nb_zero(
+	instructions: impl IntoIterator<Item = (usize, Instruction)>,
+) -> Result<i64> {
+	Dial::default().count_passes_through_target_by_line(instructions)
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Dial {
+	size: i64,
+	position: i64,
+	target: i64,
+}
+
+impl Dial {
+	pub fn new(size: i64, position: i64, target: i64) -> Result<Self> {
+		if size <= 0 {
+			return Err(error!("Invalid dial size: {size}"));
+		}
+		if !(0..size).contains(&position) || !(0..size).contains(&target) {
+			return Err(error!(
+				"Position {position} and target {target} must be between 0 and {size}"
+			));
+		}
+		Ok(Self {
+			size,
+			position,
+			target,
+		})
+	}
+
+	pub fn size(&self) -> i64 {
+		self.size
+	}
+
+	pub fn position(&self) -> i64 {
+		self.position
+	}
+
+	pub fn target(&self) -> i64 {
+		self.target
+	}
+
+	fn first_click_on_target(&self, direction: Direction) -> i64 {
+		let distance = match direction {
+			Direction::Left => self.position - self.target,
+			Direction::Right => self.target - self.position,
+		};
+		match distance.rem_euclid(self.size) {
+			0 => self.size,
+			distance => distance,
+		}
+	}
+
+	fn rotate(&mut self, index: usize, instruction: Instruction) -> Rotation {
+		let from = self.position;
+		let first_crossing = self.first_click_on_target(instruction.direction);
+		let nb_crossings = if instruction.amount < first_crossing {
+			0
+		} else {
+			(instruction.amount - first_crossing) / self.size + 1
+		};
+		let offset = instruction.amount.rem_euclid(self.size);
+		let position = match instruction.direction {
+			Direction::Left => from - offset,
+			Direction::Right => from + offset,
+		};
+		self.position = position.rem_euclid(self.size);
+		Rotation {
+			index,
+			instruction,
+			from,
+			to: self.position,
+			first_crossing,
+			nb_crossings,
+		}
+	}
+
+	pub fn rotations(
+		mut self,
+		instructions: impl IntoIterator<Item = Instruction>,
+	) -> impl Iterator<Item = Rotation> {
+		instructions
+			.into_iter()
+			.enumerate()
+			.map(move |(index, instruction)| self.rotate(index, instruction))
+	}
+
+	pub fn events(
+		self,
+		instructions: impl IntoIterator<Item = Instruction>,
+	) -> impl Iterator<Item = DialEvent> {
+		let size = self.size;
+		self.rotations(instructions).flat_map(move |rotation| {
+			let crossings = (0..rotation.nb_crossings).map(move |i| {
+				let click = rotation.first_crossing + i * size;
+				DialEvent::Crossing {
+					index: rotation.index,
+					click,
+					landed: click == rotation.instruction.amount,
+				}
+			});
+			iter::once(DialEvent::Rotation {
+				index: rotation.index,
+				instruction: rotation.instruction,
+				from: rotation.from,
+				to: rotation.to,
+			})
+			.chain(crossings)
+		})
+	}
+
+	pub fn count_stops_at_target(
+		self,
+		instructions: impl IntoIterator<Item = Instruction>,
+	) -> usize {
+		let target = self.target;
+		self.rotations(instructions)
+			.filter(|rotation| rotation.to == target)
+			.count()
+	}
+
+	pub fn count_passes_through_target(
+		self,
+		instructions: impl IntoIterator<Item = Instruction>,
+	) -> Result<i64> {
+		self.count_passes_through_target_by_line((1..).zip(instructions))
+	}
+
+	pub fn count_passes_through_target_by_line(
+		mut self,
+		instructions: impl IntoIterator<Item = (usize, Instruction)>,
+	) -> Result<i64> {
+		instructions.into_iter().enumerate().try_fold(
+			0i64,
+			|nb_passes, (index, (line, instruction))| {
+				let rotation = self.rotate(index, instruction);
+				nb_passes.checked_add(rotation.nb_crossings).ok_or_else(|| {
+					error!("Too many passes through target on line {line}: {instruction}")
+				})
+			},
+		)
+	}
+
+	pub fn analyse_start_positions(
+		&self,
+		instructions: &[Instruction],
+	) -> Result<StartPositionAnalysis> {
+		let size = self.size as usize;
+		let mut nb_stops = vec![0; size];
+		let mut pass_differences = vec![0; size + 1];
+		let mut nb_full_turns = 0i64;
+		let mut displacement = 0;
+
+		for (index, instruction) in instructions.iter().enumerate() {
+			nb_full_turns = nb_full_turns
+				.checked_add(instruction.amount.div_euclid(self.size))
+				.ok_or_else(|| {
+					error!(
+						"Too many passes through target at instruction {}: {instruction}",
+						index + 1
+					)
+				})?;
+			let remainder = instruction.amount.rem_euclid(self.size);
+			let (first_start, displacement_after) = match instruction.direction {
+				Direction::Left => (self.target + 1 - displacement, displacement - remainder),
+				Direction::Right => (
+					self.target - displacement - remainder,
+					displacement + remainder,
+				),
+			};
+			let first_start = first_start.rem_euclid(self.size) as usize;
+			let last_start = first_start + remainder as usize;
+			pass_differences[first_start] += 1;
+			if last_start <= size {
+				pass_differences[last_start] -= 1;
+			} else {
+				pass_differences[0] += 1;
+				pass_differences[last_start - size] -= 1;
+			}
+
+			displacement = displacement_after.rem_euclid(self.size);
+			nb_stops[(self.target - displacement).rem_euclid(self.size) as usize] += 1;
+		}
+
+		let nb_passes = pass_differences[..size]
+			.iter()
+			.scan(nb_full_turns, |nb_passes, difference| {
+				*nb_passes += difference;
+				Some(*nb_passes)
+			})
+			.collect();
+		Ok(StartPositionAnalysis {
+			nb_stops,
+			nb_passes,
+		})
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Rotation {
+	pub index: usize,
+	pub instruction: Instruction,
+	pub from: i64,
+	pub to: i64,
+	pub first_crossing: i64,
+	pub nb_crossings: i64,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct StartPositionAnalysis {
+	pub nb_stops: Vec<usize>,
+	pub nb_passes: Vec<i64>,
+}
+
+impl StartPositionAnalysis {
+	pub fn most_stops(&self) -> Option<(i64, usize)> {
+		extreme(&self.nb_stops, Ordering::Greater)
+	}
+
+	pub fn fewest_stops(&self) -> Option<(i64, usize)> {
+		extreme(&self.nb_stops, Ordering::Less)
+	}
+
+	pub fn most_passes(&self) -> Option<(i64, i64)> {
+		extreme(&self.nb_passes, Ordering::Greater)
+	}
+
+	pub fn fewest_passes(&self) -> Option<(i64, i64)> {
+		extreme(&self.nb_passes, Ordering::Less)
+	}
+}
+
+fn extreme<T: Ord + Copy>(counts: &[T], ordering: Ordering) -> Option<(i64, T)> {
+	counts
+		.iter()
+		.enumerate()
+		.reduce(|best, current| {
+			if current.1.cmp(best.1) == ordering {
+				current
+			} else {
+				best
+			}
+		})
+		.map(|(position, count)| (position as i64, *count))
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DialEvent {
+	Rotation {
+		index: usize,
+		instruction: Instruction,
+		from: i64,
+		to: i64,
+	},
+	Crossing {
+		index: usize,
+		click: i64,
+		landed: bool,
+	},
+}
+
+impl Display for DialEvent {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			DialEvent::Rotation {
+				index,
+				instruction,
+				from,
+				to,
+			} => write!(f, "#{} {instruction}: {from} -> {to}", index + 1),
+			DialEvent::Crossing {
+				click,
+				landed: true,
+				..
+			} => write!(f, "  lands on target after {click} clicks"),
+			DialEvent::Crossing { click, .. } => {
+				write!(f, "  passes target after {click} clicks")
+			}
+		}
+	}
+}
+
+impl Default for Dial {
+	fn default() -> Self {
+		Self {
+			size: 100,
+			position: 50,
+			target: 0,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::year2025::day1::Direction::{Left, Right};
+
+	const EXAMPLE: &[u8] = b"\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+	#[test]
+	fn parse_instructions_should_parse_example() {
+		assert_eq!(
+			parse_instructions(EXAMPLE).unwrap(),
+			vec![
+				Instruction::new(Left, 68),
+				Instruction::new(Left, 30),
+				Instruction::new(Right, 48),
+				Instruction::new(Left, 5),
+				Instruction::new(Right, 60),
+				Instruction::new(Left, 55),
+				Instruction::new(Left, 1),
+				Instruction::new(Left, 99),
+				Instruction::new(Right, 14),
+				Instruction::new(Left, 82),
+			]
+		);
+	}
+
+	#[test]
+	fn execute_instructions_and_count_nb_stops_at_zero_should_return_3_for_example() {
+		let instructions = parse_instructions(EXAMPLE).unwrap();
+		assert_eq!(
+			execute_instructions_and_count_nb_stops_at_zero(instructions),
+			3,
+		);
+	}
+
+	#[test]
+	fn execute_instructions_and_count_nb_zero_should_return_6_for_example() {
+		let instructions = parse_numbered_instructions(EXAMPLE).unwrap();
+		assert_eq!(
+			execute_instructions_and_count_nb_zero(instructions).unwrap(),
+			6
+		);
+	}
+
+	#[test]
+	fn execute_instructions_and_count_nb_zero_should_return_2_for_l50_r101() {
+		let instructions = vec![
+			(1, Instruction::new(Left, 50)),
+			(2, Instruction::new(Right, 101)),
+		];
+		assert_eq!(
+			execute_instructions_and_count_nb_zero(instructions).unwrap(),
+			2
+		);
+	}
+
+	#[test]
+	fn stream_part_two_should_count_6_zeros_for_example() {
+		let result = Day1::new("").stream_part_two(&mut &EXAMPLE[..]).unwrap();
+		assert_eq!(result, "Number of times the dial passes zero: 6");
+	}
+
+	fn count_by_clicking(
+		size: i64,
+		position: i64,
+		target: i64,
+		instructions: &[Instruction],
+	) -> (usize, i64) {
+		let mut position = position;
+		let mut nb_stops = 0;
+		let mut nb_passes = 0;
+		for instruction in instructions {
+			let step = match instruction.direction {
+				Left => -1,
+				Right => 1,
+			};
+			for _ in 0..instruction.amount {
+				position = (position + step).rem_euclid(size);
+				if position == target {
+					nb_passes += 1;
+				}
+			}
+			if position == target {
+				nb_stops += 1;
+			}
+		}
+		(nb_stops, nb_passes)
+	}
+
+	#[test]
+	fn dial_new_should_reject_position_outside_dial() {
+		assert!(Dial::new(10, 10, 0).is_err());
+		assert!(Dial::new(10, 0, -1).is_err());
+		assert!(Dial::new(0, 0, 0).is_err());
+	}
+
+	#[test]
+	fn dial_count_passes_through_target_should_count_landing_and_leaving_target_once() {
+		let dial = Dial::new(10, 3, 3).unwrap();
+		let instructions = vec![Instruction::new(Right, 10), Instruction::new(Left, 20)];
+		assert_eq!(dial.count_passes_through_target(instructions).unwrap(), 3);
+	}
+
+	#[test]
+	fn dial_counts_should_match_clicking_for_any_size_position_and_target() {
+		let instructions = parse_instructions(EXAMPLE).unwrap();
+		for size in [1, 2, 7, 10, 100, 101] {
+			for position in 0..size {
+				for target in [0, size / 2, size - 1] {
+					let dial = Dial::new(size, position, target).unwrap();
+					let (nb_stops, nb_passes) =
+						count_by_clicking(size, position, target, &instructions);
+					assert_eq!(
+						dial.count_stops_at_target(instructions.iter().copied()),
+						nb_stops
+					);
+					assert_eq!(
+						dial.count_passes_through_target(instructions.iter().copied())
+							.unwrap(),
+						nb_passes
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn dial_events_should_report_rotations_and_crossings() {
+		let instructions = vec![
+			Instruction::new(Left, 50),
+			Instruction::new(Right, 101),
+			Instruction::new(Right, 0),
+		];
+		assert_eq!(
+			Dial::default().events(instructions).collect::<Vec<_>>(),
+			vec![
+				DialEvent::Rotation {
+					index: 0,
+					instruction: Instruction::new(Left, 50),
+					from: 50,
+					to: 0,
+				},
+				DialEvent::Crossing {
+					index: 0,
+					click: 50,
+					landed: true,
+				},
+				DialEvent::Rotation {
+					index: 1,
+					instruction: Instruction::new(Right, 101),
+					from: 0,
+					to: 1,
+				},
+				DialEvent::Crossing {
+					index: 1,
+					click: 100,
+					landed: false,
+				},
+				DialEvent::Rotation {
+					index: 2,
+					instruction: Instruction::new(Right, 0),
+					from: 1,
+					to: 1,
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn dial_events_should_number_rotations_from_1() {
+		let events: Vec<_> = Dial::default()
+			.events([Instruction::new(Left, 68), Instruction::new(Left, 30)])
+			.map(|event| event.to_string())
+			.collect();
+		assert_eq!(
+			events,
+			vec![
+				"#1 L68: 50 -> 82",
+				"  passes target after 50 clicks",
+				"#2 L30: 82 -> 52",
+			]
+		);
+	}
+
+	#[test]
+	fn analyse_start_positions_should_match_dial_counts_for_every_start_position() {
+		let mut instructions = parse_instructions(EXAMPLE).unwrap();
+		instructions.extend([
+			Instruction::new(Right, 0),
+			Instruction::new(Left, 250),
+			Instruction::new(Right, 1000),
+		]);
+		for size in [1, 3, 10, 100] {
+			for target in [0, size / 3, size - 1] {
+				let analysis = Dial::new(size, 0, target)
+					.unwrap()
+					.analyse_start_positions(&instructions)
+					.unwrap();
+				for position in 0..size {
+					let dial = Dial::new(size, position, target).unwrap();
+					assert_eq!(
+						analysis.nb_stops[position as usize],
+						dial.count_stops_at_target(instructions.iter().copied()),
+					);
+					assert_eq!(
+						analysis.nb_passes[position as usize],
+						dial.count_passes_through_target(instructions.iter().copied())
+							.unwrap(),
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn analyse_start_positions_should_report_best_and_worst_positions() {
+		let instructions = vec![Instruction::new(Right, 5), Instruction::new(Left, 2)];
+		let analysis = Dial::new(10, 0, 0)
+			.unwrap()
+			.analyse_start_positions(&instructions)
+			.unwrap();
+		assert_eq!(analysis.nb_stops, vec![0, 0, 0, 0, 0, 1, 0, 1, 0, 0]);
+		assert_eq!(analysis.most_stops(), Some((5, 1)));
+		assert_eq!(analysis.fewest_stops(), Some((0, 0)));
+		assert_eq!(analysis.most_passes(), Some((6, 2)));
+		assert_eq!(analysis.fewest_passes(), Some((0, 0)));
+	}
+
+	#[test]
+	fn parse_instructions_should_name_the_invalid_line() {
+		let error = parse_instructions(b"L68\nR99999999999999999999\n").unwrap_err();
+		assert!(
+			error.0.starts_with("Invalid instruction on line 2"),
+			"{}",
+			error.0
+		);
+	}
+
+	#[test]
+	fn execute_instructions_and_count_nb_zero_should_count_passes_of_huge_amounts() {
+		let instructions = vec![
+			(1, Instruction::new(Right, 9_000_000_000_000_000_000)),
+			(2, Instruction::new(Left, 5_000_000_000)),
+		];
+		assert_eq!(
+			execute_instructions_and_count_nb_zero(instructions).unwrap(),
+			90_000_000_000_000_000 + 50_000_000
+		);
+	}
+
+	#[test]
+	fn dial_count_passes_through_target_should_report_overflowing_instruction() {
+		let dial = Dial::new(1, 0, 0).unwrap();
+		let instructions = vec![Instruction::new(Right, i64::MAX), Instruction::new(Left, 1)];
+		let error = dial.count_passes_through_target(instructions).unwrap_err();
+		assert_eq!(error.0, "Too many passes through target on line 2: L1");
+	}
+
+	#[test]
+	fn execute_instructions_and_count_nb_zero_should_name_the_overflowing_line() {
+		let program = b"# spin\nL1\n\n200x{R9223372036854775807}\n";
+		let instructions = parse_numbered_instructions(program).unwrap();
+		let error = execute_instructions_and_count_nb_zero(instructions).unwrap_err();
+		assert_eq!(
+			error.0,
+			"Too many passes through target on line 4: R9223372036854775807"
+		);
+	}
+
+	#[test]
+	fn instruction_from_str_should_reject_signed_and_empty_amounts() {
+		assert!("L-5".parse::<Instruction>().is_err());
+		assert!("R+5".parse::<Instruction>().is_err());
+		assert!("".parse::<Instruction>().is_err());
+		assert!("é5".parse::<Instruction>().is_err());
+	}
+
+	#[test]
+	fn stream_part_two_should_skip_comments_and_blank_lines() {
+		let input = b"# example\nL68 L30\n\nR48 # half a turn\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+		let result = Day1::new("").stream_part_two(&mut &input[..]).unwrap();
+		assert_eq!(result, "Number of times the dial passes zero: 6");
+	}
+
+	#[test]
+	fn stream_part_two_should_name_the_invalid_line() {
+		let error = Day1::new("")
+			.stream_part_two(&mut &b"L68\n\nR-5\n"[..])
+			.unwrap_err();
+		assert!(
+			error.0.starts_with("Invalid instruction on line 3"),
+			"{}",
+			error.0
+		);
+	}
+
+	#[test]
+	fn stream_part_one_should_report_invalid_utf8() {
+		let error = Day1::new("")
+			.stream_part_one(&mut &b"L68\nR\xff\nL5\n"[..])
+			.unwrap_err();
+		assert!(error.0.starts_with("IO error"), "{}", error.0);
+	}
+
+	#[test]
+	fn stream_part_two_should_require_separators_between_instructions() {
+		let error = Day1::new("")
+			.stream_part_two(&mut &b"L68R5\n"[..])
+			.unwrap_err();
+		assert!(
+			error.0.starts_with("Invalid instruction on line 1"),
+			"{}",
+			error.0
+		);
+	}
+
+	#[test]
+	fn stream_part_two_should_reject_repetitions_and_macros() {
+		for input in ["L68\n3x{R10}\n", "def spin { R1 }\n", "@spin\n"] {
+			let error = Day1::new("")
+				.stream_part_two(&mut input.as_bytes())
+				.unwrap_err();
+			assert!(error.0.starts_with("Repetitions and macros"), "{}", error.0);
+		}
+	}
+}
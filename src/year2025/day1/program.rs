@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::iter;
+use winnow::ascii::{alphanumeric1, digit1, multispace1, space1, till_line_ending};
+use winnow::combinator::{alt, delimited, eof, peek, preceded, repeat, separated_pair, terminated};
+use winnow::stream::Offset;
+use winnow::token::one_of;
+use winnow::{ModalResult, Parser};
+
+use super::{Direction, Instruction};
+use crate::{Error, Result, error};
+
+const MAX_INSTRUCTIONS: usize = 10_000_000;
+
+#[derive(Debug, Eq, PartialEq)]
+enum Item<'a> {
+	Instruction(&'a str, Instruction),
+	Repeat(usize, Vec<Item<'a>>),
+	Call(&'a str),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Statement<'a> {
+	Definition(&'a str, Vec<Item<'a>>),
+	Item(Item<'a>),
+}
+
+pub fn parse_program(input: &str) -> Result<Vec<(usize, Instruction)>> {
+	let statements: Vec<_> = terminated(repeat(0.., statement), (ignored, eof))
+		.parse(input)
+		.map_err(|e| {
+			let line = input[..e.offset()].matches('\n').count() + 1;
+			error!("Invalid instruction on line {line}")
+		})?;
+
+	let mut macros = HashMap::new();
+	let mut items = Vec::new();
+	for statement in statements {
+		match statement {
+			Statement::Definition(name, body) => {
+				if macros.insert(name, body).is_some() {
+					return Err(error!("Macro {name} is defined twice"));
+				}
+			}
+			Statement::Item(item) => items.push(item),
+		}
+	}
+
+	let line_starts: Vec<_> = iter::once(0)
+		.chain(input.match_indices('\n').map(|(i, _)| i + 1))
+		.collect();
+	let line_of =
+		|text: &str| line_starts.partition_point(|&start| start <= text.offset_from(&input));
+	let mut instructions = Vec::new();
+	expand(
+		&items,
+		&macros,
+		&line_of,
+		&mut Vec::new(),
+		&mut instructions,
+	)?;
+	Ok(instructions)
+}
+
+fn expand<'a>(
+	items: &[Item<'a>],
+	macros: &HashMap<&'a str, Vec<Item<'a>>>,
+	line_of: &impl Fn(&str) -> usize,
+	calls: &mut Vec<&'a str>,
+	instructions: &mut Vec<(usize, Instruction)>,
+) -> Result<()> {
+	for item in items {
+		match item {
+			Item::Instruction(text, instruction) => {
+				if instructions.len() == MAX_INSTRUCTIONS {
+					return Err(too_many_instructions());
+				}
+				instructions.push((line_of(text), *instruction));
+			}
+			Item::Repeat(times, body) => {
+				let start = instructions.len();
+				expand(body, macros, line_of, calls, instructions)?;
+				let end = instructions.len();
+				(end - start)
+					.checked_mul(*times)
+					.and_then(|size| size.checked_add(start))
+					.filter(|&len| len <= MAX_INSTRUCTIONS)
+					.ok_or_else(too_many_instructions)?;
+				if *times == 0 {
+					instructions.truncate(start);
+				} else if start < end {
+					for _ in 1..*times {
+						instructions.extend_from_within(start..end);
+					}
+				}
+			}
+			Item::Call(name) => {
+				if calls.contains(name) {
+					return Err(error!("Macro {name} calls itself"));
+				}
+				let body = macros
+					.get(name)
+					.ok_or_else(|| error!("Unknown macro {name}"))?;
+				calls.push(name);
+				expand(body, macros, line_of, calls, instructions)?;
+				calls.pop();
+			}
+		}
+	}
+	Ok(())
+}
+
+fn too_many_instructions() -> Error {
+	error!("Programs cannot expand to more than {MAX_INSTRUCTIONS} instructions")
+}
+
+fn ignored(input: &mut &str) -> ModalResult<()> {
+	repeat(
+		0..,
+		alt((multispace1.void(), ('#', till_line_ending).void())),
+	)
+	.parse_next(input)
+}
+
+fn statement<'a>(input: &mut &'a str) -> ModalResult<Statement<'a>> {
+	preceded(
+		ignored,
+		terminated(alt((definition, item.map(Statement::Item))), separator),
+	)
+	.parse_next(input)
+}
+
+fn separator(input: &mut &str) -> ModalResult<()> {
+	peek(alt((
+		multispace1.void(),
+		"#".void(),
+		"}".void(),
+		eof.void(),
+	)))
+	.parse_next(input)
+}
+
+fn definition<'a>(input: &mut &'a str) -> ModalResult<Statement<'a>> {
+	preceded(
+		("def", space1),
+		separated_pair(alphanumeric1, ignored, block),
+	)
+	.map(|(name, body)| Statement::Definition(name, body))
+	.parse_next(input)
+}
+
+fn item<'a>(input: &mut &'a str) -> ModalResult<Item<'a>> {
+	alt((
+		instruction
+			.with_taken()
+			.map(|(instruction, text)| Item::Instruction(text, instruction)),
+		repetition,
+		call,
+	))
+	.parse_next(input)
+}
+
+fn instruction(input: &mut &str) -> ModalResult<Instruction> {
+	(
+		one_of(['L', 'R']).map(|direction| {
+			if direction == 'L' {
+				Direction::Left
+			} else {
+				Direction::Right
+			}
+		}),
+		digit1.parse_to(),
+	)
+		.map(|(direction, amount)| Instruction::new(direction, amount))
+		.parse_next(input)
+}
+
+fn repetition<'a>(input: &mut &'a str) -> ModalResult<Item<'a>> {
+	separated_pair(
+		digit1.parse_to(),
+		'x',
+		alt((block, call.map(|call| vec![call]))),
+	)
+	.map(|(times, body)| Item::Repeat(times, body))
+	.parse_next(input)
+}
+
+fn call<'a>(input: &mut &'a str) -> ModalResult<Item<'a>> {
+	preceded('@', alphanumeric1)
+		.map(Item::Call)
+		.parse_next(input)
+}
+
+fn block<'a>(input: &mut &'a str) -> ModalResult<Vec<Item<'a>>> {
+	delimited(
+		'{',
+		repeat(0.., preceded(ignored, terminated(item, separator))),
+		(ignored, '}'),
+	)
+	.parse_next(input)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::year2025::day1::Direction::{Left, Right};
+
+	#[test]
+	fn parse_program_should_ignore_comments_and_blank_lines() {
+		let program = "# warm up\nL68\n\n  R48 # half a turn\n";
+		assert_eq!(
+			parse_program(program).unwrap(),
+			vec![
+				(2, Instruction::new(Left, 68)),
+				(4, Instruction::new(Right, 48))
+			]
+		);
+	}
+
+	#[test]
+	fn parse_program_should_expand_nested_repetitions() {
+		assert_eq!(
+			parse_program("2x{R10 2x{L5}}").unwrap(),
+			vec![
+				(1, Instruction::new(Right, 10)),
+				(1, Instruction::new(Left, 5)),
+				(1, Instruction::new(Left, 5)),
+				(1, Instruction::new(Right, 10)),
+				(1, Instruction::new(Left, 5)),
+				(1, Instruction::new(Left, 5)),
+			]
+		);
+	}
+
+	#[test]
+	fn parse_program_should_expand_macros() {
+		let program = "\
+def wiggle {
+	R10 L5
+}
+def twice { 2x@wiggle }
+@twice
+L1
+";
+		assert_eq!(
+			parse_program(program).unwrap(),
+			vec![
+				(2, Instruction::new(Right, 10)),
+				(2, Instruction::new(Left, 5)),
+				(2, Instruction::new(Right, 10)),
+				(2, Instruction::new(Left, 5)),
+				(6, Instruction::new(Left, 1)),
+			]
+		);
+	}
+
+	#[test]
+	fn parse_program_should_reject_recursive_macros() {
+		assert!(parse_program("def loop { @loop }\n@loop").is_err());
+	}
+
+	#[test]
+	fn parse_program_should_reject_unknown_macros() {
+		assert!(parse_program("@spin").is_err());
+	}
+
+	#[test]
+	fn parse_program_should_name_the_invalid_line() {
+		let error = parse_program("L68\nR5\nX12\n").unwrap_err();
+		assert_eq!(error.0, "Invalid instruction on line 3");
+	}
+
+	#[test]
+	fn parse_program_should_reject_huge_expansions() {
+		let error = parse_program("4000000000x{L1}").unwrap_err();
+		assert_eq!(
+			error.0,
+			"Programs cannot expand to more than 10000000 instructions"
+		);
+		assert!(parse_program("def a { 5000x{R1} }\n5000x@a").is_err());
+		assert_eq!(parse_program("4000000000x{} 0x{L1}").unwrap(), vec![]);
+	}
+
+	#[test]
+	fn parse_program_should_require_separators_between_items() {
+		assert_eq!(
+			parse_program("L68R5").unwrap_err().0,
+			"Invalid instruction on line 1"
+		);
+		assert!(parse_program("2x{R1L2}").is_err());
+		assert!(parse_program("def a { R1 }@a").is_err());
+		assert_eq!(parse_program("2x{R1}#twice").unwrap().len(), 2);
+	}
+}
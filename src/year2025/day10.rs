@@ -12,18 +12,26 @@ use winnow::{ModalResult, Parser};
 use crate::input::{ParseExt, ReadLines};
 use crate::{Error, Result, Solution};
 
-const INPUT: &[u8] = include_bytes!("../input/day10");
+pub struct Day10<'a> {
+	input: &'a str,
+	machines: OnceCell<Vec<Machine>>,
+}
 
-#[derive(Default)]
-pub struct Day10(OnceCell<Vec<Machine>>);
+impl<'a> Day10<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			machines: OnceCell::new(),
+		}
+	}
 
-impl Day10 {
 	fn machines(&self) -> Result<&Vec<Machine>> {
-		self.0.get_or_try_init(|| parse_machines(INPUT))
+		self.machines
+			.get_or_try_init(|| parse_machines(self.input.as_bytes()))
 	}
 }
 
-impl Solution for Day10 {
+impl Solution for Day10<'_> {
 	fn part_one(&self) -> Result<String> {
 		let presses: usize = self
 			.machines()?
@@ -7,18 +7,25 @@ use winnow::{Parser, seq};
 
 use crate::{Result, Solution};
 
-const INPUT: &str = include_str!("../input/day11");
+pub struct Day11<'a> {
+	input: &'a str,
+	devices: OnceCell<HashMap<&'a str, Device<'a>>>,
+}
 
-#[derive(Default)]
-pub struct Day11(OnceCell<HashMap<&'static str, Device<'static>>>);
+impl<'a> Day11<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			devices: OnceCell::new(),
+		}
+	}
 
-impl Day11 {
-	fn devices(&self) -> Result<&HashMap<&'static str, Device<'static>>> {
-		self.0.get_or_try_init(|| parse_devices(INPUT))
+	fn devices(&self) -> Result<&HashMap<&'a str, Device<'a>>> {
+		self.devices.get_or_try_init(|| parse_devices(self.input))
 	}
 }
 
-impl Solution for Day11 {
+impl Solution for Day11<'_> {
 	fn part_one(&self) -> Result<String> {
 		let nb_paths = count_paths_from_you_to_out(self.devices()?);
 		Ok(format!("Number of paths from you to out: {nb_paths}"))
@@ -146,7 +153,7 @@ hhh: out
 
 	macro_rules! device {
 		($name: expr => $($o:expr),+$(,)?) => {
-			crate::day11::Device {
+			crate::year2025::day11::Device {
 				name: $name,
 				output: vec![$($o),+],
 			}
@@ -0,0 +1,833 @@
+use itertools::{Itertools, process_results};
+use std::cell::OnceCell;
+use std::fmt::{self, Display, Formatter};
+use std::io::{Read, Write};
+use std::ops::RangeInclusive;
+
+use crate::input::ReadSplit;
+use crate::year2025::Radix;
+use crate::{Result, Solution, error};
+pub use rule::{Match, Rule};
+
+mod rule;
+
+pub struct Day2<'a> {
+	input: &'a str,
+	instruction_ranges: OnceCell<Vec<RangeInclusive<u64>>>,
+}
+
+impl<'a> Day2<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			instruction_ranges: OnceCell::new(),
+		}
+	}
+
+	fn instruction_ranges(&self) -> Result<&Vec<RangeInclusive<u64>>> {
+		self.instruction_ranges
+			.get_or_try_init(|| parse_instruction_ranges(self.input))
+	}
+}
+
+impl Solution for Day2<'_> {
+	fn part_one(&self) -> Result<String> {
+		let sum_of_invalid_ids = sum_invalid_ids(
+			normalise_ranges(self.instruction_ranges()?.iter().cloned()),
+			&Rule::exactly(2),
+			Radix::DECIMAL,
+		);
+		Ok(format!("Sum of invalid ids: {sum_of_invalid_ids}"))
+	}
+
+	fn part_two(&self) -> Result<String> {
+		let sum_of_invalid_ids = sum_invalid_ids(
+			normalise_ranges(self.instruction_ranges()?.iter().cloned()),
+			&Rule::at_least(2),
+			Radix::DECIMAL,
+		);
+		Ok(format!("Sum of invalid ids: {sum_of_invalid_ids}"))
+	}
+
+	fn stream_part_one(&self, input: &mut dyn Read) -> Result<String> {
+		let sum_of_invalid_ids = process_results(stream_instruction_ranges(input), |ranges| {
+			sum_invalid_ids(normalise_ranges(ranges), &Rule::exactly(2), Radix::DECIMAL)
+		})?;
+		Ok(format!("Sum of invalid ids: {sum_of_invalid_ids}"))
+	}
+
+	fn stream_part_two(&self, input: &mut dyn Read) -> Result<String> {
+		let sum_of_invalid_ids = process_results(stream_instruction_ranges(input), |ranges| {
+			sum_invalid_ids(normalise_ranges(ranges), &Rule::at_least(2), Radix::DECIMAL)
+		})?;
+		Ok(format!("Sum of invalid ids: {sum_of_invalid_ids}"))
+	}
+
+	fn trace(&self, output: &mut dyn Write) -> Result<()> {
+		let ranges = self.instruction_ranges()?;
+		for (first, second) in find_overlapping_ranges(ranges) {
+			writeln!(
+				output,
+				"Range #{} {}-{} overlaps range #{} {}-{}",
+				first + 1,
+				ranges[first].start(),
+				ranges[first].end(),
+				second + 1,
+				ranges[second].start(),
+				ranges[second].end(),
+			)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+fn find_invalid_ids_part1(
+	id_ranges: impl IntoIterator<Item = RangeInclusive<u64>>,
+) -> impl Iterator<Item = u64> {
+	id_ranges
+		.into_iter()
+		.flatten()
+		.filter(|id| !is_valid_id_part1(*id))
+}
+
+#[cfg(test)]
+fn find_invalid_ids_part2(
+	id_ranges: impl IntoIterator<Item = RangeInclusive<u64>>,
+) -> impl Iterator<Item = u64> {
+	id_ranges
+		.into_iter()
+		.flatten()
+		.filter(|id| !is_valid_id_part2(*id))
+}
+
+pub fn invalid_ids(
+	range: RangeInclusive<u64>,
+	rule: &Rule,
+	radix: Radix,
+) -> impl Iterator<Item = u64> {
+	let (start, end) = range.into_inner();
+	(nb_digits(start, radix)..=nb_digits(end, radix))
+		.flat_map(move |nb_digits| candidates(rule, start..=end, nb_digits, radix))
+}
+
+// IDs of the range with nb_digits digits matching the rule, in increasing order. Only the IDs
+// generated by the rule are visited: palindromes are built from their first half and IDs matching
+// all of several rules are filtered from the IDs generated by the first one.
+fn candidates<'a>(
+	rule: &'a Rule,
+	range: RangeInclusive<u64>,
+	nb_digits: u32,
+	radix: Radix,
+) -> Box<dyn Iterator<Item = u64> + 'a> {
+	match rule {
+		Rule::Repetition { .. } => Box::new(
+			rule.pattern_sizes(nb_digits)
+				.unwrap_or_default()
+				.into_iter()
+				.filter_map(move |size| repeated_patterns(range.clone(), nb_digits, size, radix))
+				.map(|(multiplier, patterns)| patterns.map(move |pattern| pattern * multiplier))
+				.kmerge()
+				.dedup(),
+		),
+		Rule::Palindrome => Box::new(palindromes(range, nb_digits, radix)),
+		Rule::Any(rules) => Box::new(
+			rules
+				.iter()
+				.map(|rule| candidates(rule, range.clone(), nb_digits, radix))
+				.kmerge()
+				.dedup(),
+		),
+		Rule::All(rules) => match rules.split_first() {
+			Some((first, others)) => Box::new(
+				candidates(first, range, nb_digits, radix)
+					.filter(move |id| others.iter().all(|rule| rule.matches_in_radix(*id, radix))),
+			),
+			None => Box::new(range.filter(move |id| self::nb_digits(*id, radix) == nb_digits)),
+		},
+	}
+}
+
+fn palindromes(
+	range: RangeInclusive<u64>,
+	nb_digits: u32,
+	radix: Radix,
+) -> impl Iterator<Item = u64> {
+	let radix = radix.get() as u64;
+	let (start, end) = range.into_inner();
+	let nb_mirrored = nb_digits / 2;
+	let nb_first_half = nb_digits - nb_mirrored;
+	let first = if nb_digits == 1 {
+		0
+	} else {
+		radix.pow(nb_first_half - 1)
+	};
+	let first = first.max(start / radix.pow(nb_mirrored));
+	(first..radix.pow(nb_first_half))
+		.map_while(move |first_half| {
+			let mut mirrored = first_half / radix.pow(nb_first_half - nb_mirrored);
+			(0..nb_mirrored).try_fold(first_half, |palindrome, _| {
+				let digit = mirrored % radix;
+				mirrored /= radix;
+				palindrome.checked_mul(radix)?.checked_add(digit)
+			})
+		})
+		.skip_while(move |id| *id < start)
+		.take_while(move |id| *id <= end)
+}
+
+pub fn count_invalid_ids(
+	id_ranges: impl IntoIterator<Item = RangeInclusive<u64>>,
+	rule: &Rule,
+	radix: Radix,
+) -> u64 {
+	id_ranges
+		.into_iter()
+		.map(|range| count_and_sum_invalid_ids(range, rule, radix).0)
+		.sum()
+}
+
+pub fn sum_invalid_ids(
+	id_ranges: impl IntoIterator<Item = RangeInclusive<u64>>,
+	rule: &Rule,
+	radix: Radix,
+) -> u128 {
+	id_ranges
+		.into_iter()
+		.map(|range| count_and_sum_invalid_ids(range, rule, radix).1)
+		.sum()
+}
+
+// Rules made of repetitions are counted in closed form. Other rules enumerate the IDs they generate,
+// so palindromes take time proportional to the square root of the range.
+fn count_and_sum_invalid_ids(range: RangeInclusive<u64>, rule: &Rule, radix: Radix) -> (u64, u128) {
+	let (start, end) = range.into_inner();
+	if start > end {
+		return (0, 0);
+	}
+	let below_start = match start.checked_sub(1) {
+		Some(limit) => count_and_sum_invalid_ids_up_to(limit, rule, radix),
+		None => Some((0, 0)),
+	};
+	match (
+		count_and_sum_invalid_ids_up_to(end, rule, radix),
+		below_start,
+	) {
+		(Some((count, sum)), Some((count_below, sum_below))) => {
+			(count - count_below, sum - sum_below)
+		}
+		_ => invalid_ids(start..=end, rule, radix)
+			.fold((0, 0), |(count, sum), id| (count + 1, sum + id as u128)),
+	}
+}
+
+pub fn count_and_sum_invalid_ids_up_to(
+	limit: u64,
+	rule: &Rule,
+	radix: Radix,
+) -> Option<(u64, u128)> {
+	let (mut count, mut sum) = (0_i128, 0_i128);
+	for nb_digits in 1..=nb_digits(limit, radix) {
+		for (size, sign) in inclusion_exclusion(&rule.pattern_sizes(nb_digits)?) {
+			if let Some((multiplier, patterns)) =
+				repeated_patterns(0..=limit, nb_digits, size, radix)
+			{
+				let (first, last) = patterns.into_inner();
+				let nb_patterns = (last - first + 1) as i128;
+				let sum_of_patterns = (first as i128 + last as i128) * nb_patterns / 2;
+				count += sign * nb_patterns;
+				sum += sign * sum_of_patterns * multiplier as i128;
+			}
+		}
+	}
+	Some((count as u64, sum as u128))
+}
+
+// IDs repeating patterns of sizes a and b also repeat a pattern of size gcd(a, b)
+fn inclusion_exclusion(sizes: &[u32]) -> Vec<(u32, i128)> {
+	let sizes: Vec<_> = sizes
+		.iter()
+		.copied()
+		.filter(|size| {
+			!sizes
+				.iter()
+				.any(|other| other != size && other.is_multiple_of(*size))
+		})
+		.collect();
+	(1..1_usize << sizes.len())
+		.map(|subset| {
+			let (size, nb_sizes) = sizes
+				.iter()
+				.enumerate()
+				.filter(|(i, _)| subset & (1 << i) != 0)
+				.fold((0, 0), |(size, n), (_, other)| (gcd(size, *other), n + 1));
+			(size, if nb_sizes % 2 == 1 { 1 } else { -1 })
+		})
+		.collect()
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+	if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn repeated_patterns(
+	range: RangeInclusive<u64>,
+	nb_digits: u32,
+	size: u32,
+	radix: Radix,
+) -> Option<(u64, RangeInclusive<u64>)> {
+	let radix = radix.get() as u64;
+	let multiplier = (0..nb_digits / size).try_fold(0_u64, |multiplier, i| {
+		multiplier.checked_add(radix.checked_pow(i * size)?)
+	})?;
+	let first = radix.pow(size - 1).max(range.start().div_ceil(multiplier));
+	let last = (radix.pow(size) - 1).min(range.end() / multiplier);
+	(first <= last).then_some((multiplier, first..=last))
+}
+
+fn nb_digits(id: u64, radix: Radix) -> u32 {
+	id.checked_ilog(radix.get() as u64).unwrap_or_default() + 1
+}
+
+pub struct Explanation<'a> {
+	pub id: u64,
+	pub range: RangeInclusive<u64>,
+	pub radix: Radix,
+	pub matches: Vec<Match<'a>>,
+}
+
+impl Display for Explanation<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{} in {}-{}:",
+			self.radix.format(self.id),
+			self.radix.format(*self.range.start()),
+			self.radix.format(*self.range.end())
+		)?;
+		for (i, Match { rule, pattern }) in self.matches.iter().enumerate() {
+			let separator = if i == 0 { "" } else { "," };
+			match pattern {
+				Some((pattern, repeats)) => write!(
+					f,
+					"{separator} {} x{repeats} ({rule})",
+					self.radix.format(*pattern)
+				)?,
+				None => write!(f, "{separator} {rule}")?,
+			}
+		}
+		Ok(())
+	}
+}
+
+pub fn explain_invalid_ids<'a>(
+	id_ranges: &'a [RangeInclusive<u64>],
+	filter: RangeInclusive<u64>,
+	rule: &'a Rule,
+	radix: Radix,
+) -> impl Iterator<Item = Explanation<'a>> {
+	normalise_ranges(id_ranges.iter().cloned())
+		.into_iter()
+		.map(move |range| *range.start().max(filter.start())..=*range.end().min(filter.end()))
+		.filter(|range| !range.is_empty())
+		.flat_map(move |range| invalid_ids(range, rule, radix))
+		.map(move |id| Explanation {
+			id,
+			range: id_ranges
+				.iter()
+				.find(|range| range.contains(&id))
+				.cloned()
+				.unwrap_or(id..=id),
+			radix,
+			matches: rule.explain(id, radix),
+		})
+}
+
+// IDs covered by several ranges are only counted once: overlapping and adjacent ranges are merged
+// before searching for invalid IDs.
+pub fn normalise_ranges(
+	id_ranges: impl IntoIterator<Item = RangeInclusive<u64>>,
+) -> Vec<RangeInclusive<u64>> {
+	let mut id_ranges: Vec<_> = id_ranges
+		.into_iter()
+		.filter(|range| !range.is_empty())
+		.collect();
+	id_ranges.sort_unstable_by_key(|range| *range.start());
+	let mut merged: Vec<RangeInclusive<u64>> = Vec::with_capacity(id_ranges.len());
+	for range in id_ranges {
+		match merged.last_mut() {
+			Some(last) if *range.start() <= last.end().saturating_add(1) => {
+				if range.end() > last.end() {
+					*last = *last.start()..=*range.end();
+				}
+			}
+			_ => merged.push(range),
+		}
+	}
+	merged
+}
+
+pub fn find_overlapping_ranges(id_ranges: &[RangeInclusive<u64>]) -> Vec<(usize, usize)> {
+	let mut indices: Vec<_> = (0..id_ranges.len())
+		.filter(|i| !id_ranges[*i].is_empty())
+		.collect();
+	indices.sort_unstable_by_key(|i| *id_ranges[*i].start());
+	let mut overlaps = Vec::new();
+	for (position, first) in indices.iter().enumerate() {
+		for second in indices[position + 1..]
+			.iter()
+			.take_while(|second| id_ranges[**second].start() <= id_ranges[*first].end())
+		{
+			overlaps.push((*first.min(second), *first.max(second)));
+		}
+	}
+	overlaps.sort_unstable();
+	overlaps
+}
+
+pub fn parse_instruction_ranges(input: &str) -> Result<Vec<RangeInclusive<u64>>> {
+	input.split(',').map(parse_instruction_range).collect()
+}
+
+// The input is read one range at a time, but ranges still have to be merged so that IDs covered by
+// several ranges are counted once, as in the non-streaming parts: streaming keeps every parsed range
+// in memory, which is O(number of ranges) rather than the constant memory of a plain sum.
+fn stream_instruction_ranges(
+	input: impl Read,
+) -> impl Iterator<Item = Result<RangeInclusive<u64>>> {
+	input
+		.read_split(b',')
+		.map(|range| parse_instruction_range(&range?))
+}
+
+fn parse_instruction_range(range: &str) -> Result<RangeInclusive<u64>> {
+	let (start, end) = range
+		.trim()
+		.split_once('-')
+		.ok_or(error!("Invalid instruction range: {range}"))?;
+	Ok(start.parse()?..=end.parse()?)
+}
+
+#[cfg(test)]
+fn is_valid_id_part1(id: u64) -> bool {
+	!Rule::exactly(2).matches(id)
+}
+
+#[cfg(test)]
+fn is_valid_id_part2(id: u64) -> bool {
+	!Rule::at_least(2).matches(id)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+	#[test]
+	fn parse_instruction_ranges_should_parse_example() {
+		let result = parse_instruction_ranges(EXAMPLE);
+
+		assert_eq!(
+			result.unwrap(),
+			vec![
+				11..=22,
+				95..=115,
+				998..=1012,
+				1188511880..=1188511890,
+				222220..=222224,
+				1698522..=1698528,
+				446443..=446449,
+				38593856..=38593862,
+				565653..=565659,
+				824824821..=824824827,
+				2121212118..=2121212124
+			]
+		);
+	}
+
+	#[test]
+	fn is_valid_id_part1_should_return_true_for_1() {
+		assert!(is_valid_id_part1(1))
+	}
+
+	#[test]
+	fn is_valid_id_part1_should_return_false_for_11() {
+		assert!(!is_valid_id_part1(11))
+	}
+
+	#[test]
+	fn is_valid_id_part1_should_return_true_for_101() {
+		assert!(is_valid_id_part1(101))
+	}
+
+	#[test]
+	fn is_valid_id_part1_should_return_true_for_111() {
+		assert!(is_valid_id_part1(111))
+	}
+
+	#[test]
+	fn is_valid_id_part1_should_return_true_for_1011() {
+		assert!(is_valid_id_part1(1011))
+	}
+
+	#[test]
+	fn is_valid_id_part1_should_return_true_for_1110() {
+		assert!(is_valid_id_part1(1110))
+	}
+
+	#[test]
+	fn is_valid_id_part1_should_return_false_for_6464() {
+		assert!(!is_valid_id_part1(6464))
+	}
+
+	#[test]
+	fn is_valid_id_part1_should_return_false_for_123123() {
+		assert!(!is_valid_id_part1(123123))
+	}
+
+	#[test]
+	fn sum_of_invalid_ids_part1_for_example_should_be_1227775554() {
+		let instruction_ranges = parse_instruction_ranges(EXAMPLE).unwrap();
+		assert_eq!(
+			find_invalid_ids_part1(instruction_ranges).sum::<u64>(),
+			1227775554,
+		);
+	}
+
+	#[test]
+	fn is_valid_id_part2_should_return_true_for_1() {
+		assert!(is_valid_id_part2(1))
+	}
+
+	#[test]
+	fn is_valid_id_part2_should_return_false_for_11() {
+		assert!(!is_valid_id_part2(11))
+	}
+
+	#[test]
+	fn is_valid_id_part2_should_return_true_for_101() {
+		assert!(is_valid_id_part2(101))
+	}
+
+	#[test]
+	fn is_valid_id_part2_should_return_false_for_6464() {
+		assert!(!is_valid_id_part2(6464))
+	}
+
+	#[test]
+	fn is_valid_id_part2_should_return_false_for_123123123() {
+		assert!(!is_valid_id_part2(123123123))
+	}
+
+	#[test]
+	fn is_valid_id_part2_should_return_false_for_1212121212() {
+		assert!(!is_valid_id_part2(1212121212))
+	}
+
+	#[test]
+	fn is_valid_id_part2_should_return_false_for_1111111() {
+		assert!(!is_valid_id_part2(1111111))
+	}
+
+	#[test]
+	fn sum_of_invalid_ids_part2_for_example_should_be_4174379265() {
+		let instruction_ranges = parse_instruction_ranges(EXAMPLE).unwrap();
+		assert_eq!(
+			find_invalid_ids_part2(instruction_ranges).sum::<u64>(),
+			4174379265,
+		);
+	}
+
+	#[test]
+	fn stream_instruction_ranges_should_parse_example_with_trailing_newline() {
+		let input = format!("{EXAMPLE}\n");
+		let result = stream_instruction_ranges(input.as_bytes()).collect::<Result<Vec<_>>>();
+		assert_eq!(result.unwrap(), parse_instruction_ranges(EXAMPLE).unwrap());
+	}
+
+	#[test]
+	fn invalid_ids_should_match_brute_force() {
+		for rule in [
+			Rule::exactly(2),
+			Rule::at_least(2),
+			"exactly:2|exactly:3".parse().unwrap(),
+			"min-pattern:2".parse().unwrap(),
+			"at-least:2+palindrome".parse().unwrap(),
+		] {
+			for range in [0..=100_000, 95..=115, 998..=1012, 1_000_000..=1_200_000] {
+				assert_eq!(
+					invalid_ids(range.clone(), &rule, Radix::DECIMAL).collect::<Vec<_>>(),
+					range
+						.clone()
+						.filter(|id| rule.matches(*id))
+						.collect::<Vec<_>>(),
+					"{rule:?} {range:?}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn count_and_sum_invalid_ids_should_match_brute_force() {
+		for rule in [
+			Rule::exactly(2),
+			Rule::at_least(2),
+			"exactly:2|exactly:3".parse().unwrap(),
+			"min-pattern:2".parse().unwrap(),
+			"at-least:2+palindrome".parse().unwrap(),
+		] {
+			for range in [0..=200_000, 11..=22, 1111..=1111, 5_000..=500_000] {
+				let invalid_ids: Vec<_> = range.clone().filter(|id| rule.matches(*id)).collect();
+				assert_eq!(
+					count_and_sum_invalid_ids(range.clone(), &rule, Radix::DECIMAL),
+					(
+						invalid_ids.len() as u64,
+						invalid_ids.iter().map(|id| *id as u128).sum()
+					),
+					"{rule:?} {range:?}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn sum_invalid_ids_for_example_should_match_both_parts() {
+		let instruction_ranges = parse_instruction_ranges(EXAMPLE).unwrap();
+		assert_eq!(
+			sum_invalid_ids(
+				instruction_ranges.clone(),
+				&Rule::exactly(2),
+				Radix::DECIMAL
+			),
+			1227775554
+		);
+		assert_eq!(
+			sum_invalid_ids(instruction_ranges, &Rule::at_least(2), Radix::DECIMAL),
+			4174379265
+		);
+	}
+
+	#[test]
+	fn count_invalid_ids_part1_should_handle_the_whole_u64_domain() {
+		assert_eq!(
+			count_invalid_ids([0..=u64::MAX], &Rule::exactly(2), Radix::DECIMAL),
+			1_844_674_407
+		);
+	}
+
+	#[test]
+	fn invalid_ids_should_generate_the_largest_ids() {
+		let last = invalid_ids(
+			18_446_000_000_000_000_000..=u64::MAX,
+			&Rule::at_least(2),
+			Radix::DECIMAL,
+		)
+		.last()
+		.unwrap();
+		assert_eq!(last, 18_446_744_071_844_674_407);
+		assert_eq!(
+			count_invalid_ids(
+				[18_446_000_000_000_000_000..=u64::MAX],
+				&Rule::at_least(2),
+				Radix::DECIMAL,
+			),
+			invalid_ids(
+				18_446_000_000_000_000_000..=u64::MAX,
+				&Rule::at_least(2),
+				Radix::DECIMAL,
+			)
+			.count() as u64
+		);
+	}
+
+	#[test]
+	fn normalise_ranges_should_merge_overlapping_and_adjacent_ranges() {
+		assert_eq!(
+			normalise_ranges([95..=115, 11..=22, 20..=30, 31..=40, 110..=112]),
+			vec![11..=40, 95..=115]
+		);
+	}
+
+	#[test]
+	fn sum_invalid_ids_should_count_overlapping_ids_once() {
+		let instruction_ranges =
+			parse_instruction_ranges("11-22,15-33,998-1012,1000-1010").unwrap();
+		assert_eq!(
+			sum_invalid_ids(
+				normalise_ranges(instruction_ranges),
+				&Rule::at_least(2),
+				Radix::DECIMAL
+			),
+			11 + 22 + 33 + 999 + 1010
+		);
+	}
+
+	#[test]
+	fn stream_part_two_should_count_overlapping_ids_once() {
+		let result = Day2::new("")
+			.stream_part_two(&mut &b"11-22,15-33,998-1012,1000-1010"[..])
+			.unwrap();
+		assert_eq!(
+			result,
+			format!("Sum of invalid ids: {}", 11 + 22 + 33 + 999 + 1010)
+		);
+	}
+
+	#[test]
+	fn stream_part_one_should_report_invalid_utf8() {
+		let error = Day2::new("")
+			.stream_part_one(&mut &b"11-22,\xff-33,95-115"[..])
+			.unwrap_err();
+		assert!(
+			error.0.starts_with("Input is not valid UTF-8"),
+			"{}",
+			error.0
+		);
+	}
+
+	#[test]
+	fn find_overlapping_ranges_should_report_input_indices() {
+		let instruction_ranges = parse_instruction_ranges("20-30,1-5,25-40,5-6,50-60").unwrap();
+		assert_eq!(
+			find_overlapping_ranges(&instruction_ranges),
+			vec![(0, 2), (1, 3)]
+		);
+	}
+
+	#[test]
+	fn invalid_ids_should_match_brute_force_in_other_radixes() {
+		for radix in [2, 8, 16].map(|radix| Radix::new(radix).unwrap()) {
+			for rule in [Rule::exactly(2), Rule::at_least(2), Rule::Palindrome] {
+				let range = 0..=100_000;
+				assert_eq!(
+					invalid_ids(range.clone(), &rule, radix).collect::<Vec<_>>(),
+					range
+						.clone()
+						.filter(|id| rule.matches_in_radix(*id, radix))
+						.collect::<Vec<_>>(),
+					"{rule:?} {radix}"
+				);
+				assert_eq!(
+					count_invalid_ids([range.clone()], &rule, radix),
+					invalid_ids(range, &rule, radix).count() as u64,
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn count_invalid_ids_should_handle_the_whole_u64_domain_in_binary() {
+		assert_eq!(
+			count_invalid_ids([0..=u64::MAX], &Rule::exactly(2), Radix::new(2).unwrap()),
+			(1 << 32) - 1
+		);
+	}
+
+	#[test]
+	fn explain_invalid_ids_should_describe_ids_in_filter() {
+		let instruction_ranges = parse_instruction_ranges(EXAMPLE).unwrap();
+		let rule = Rule::at_least(2);
+		let explanations: Vec<_> =
+			explain_invalid_ids(&instruction_ranges, 0..=1000, &rule, Radix::DECIMAL)
+				.map(|explanation| explanation.to_string())
+				.collect();
+		assert_eq!(
+			explanations,
+			vec![
+				"11 in 11-22: 1 x2 (at-least:2)",
+				"22 in 11-22: 2 x2 (at-least:2)",
+				"99 in 95-115: 9 x2 (at-least:2)",
+				"111 in 95-115: 1 x3 (at-least:2)",
+				"999 in 998-1012: 9 x3 (at-least:2)",
+			]
+		);
+
+		let radix = Radix::new(16).unwrap();
+		let explanations: Vec<_> = explain_invalid_ids(&[16..=256], 0..=0x22, &rule, radix)
+			.map(|explanation| explanation.to_string())
+			.collect();
+		assert_eq!(
+			explanations,
+			vec![
+				"11 in 10-100: 1 x2 (at-least:2)",
+				"22 in 10-100: 2 x2 (at-least:2)"
+			]
+		);
+	}
+
+	#[test]
+	fn count_and_sum_invalid_ids_up_to_should_count_111111_once() {
+		let rule = Rule::at_least(2);
+		let (count_before, sum_before) =
+			count_and_sum_invalid_ids_up_to(111110, &rule, Radix::DECIMAL).unwrap();
+		assert_eq!(
+			count_and_sum_invalid_ids_up_to(111111, &rule, Radix::DECIMAL),
+			Some((count_before + 1, sum_before + 111111))
+		);
+	}
+
+	#[test]
+	fn count_and_sum_invalid_ids_up_to_should_match_brute_force() {
+		for rule in [Rule::exactly(2), Rule::at_least(2), Rule::at_least(3)] {
+			let mut expected = (0, 0);
+			for limit in 0..=20_000 {
+				if rule.matches(limit) {
+					expected = (expected.0 + 1, expected.1 + limit as u128);
+				}
+				assert_eq!(
+					count_and_sum_invalid_ids_up_to(limit, &rule, Radix::DECIMAL),
+					Some(expected),
+					"{rule} {limit}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn invalid_ids_should_generate_palindromes_and_combined_rules() {
+		let range = 990..=12_345;
+		for rule in [
+			"palindrome",
+			"palindrome+part2",
+			"part1|palindrome",
+			"part1+part2",
+		] {
+			let rule: Rule = rule.parse().unwrap();
+			assert_eq!(
+				invalid_ids(range.clone(), &rule, Radix::DECIMAL).collect::<Vec<_>>(),
+				range
+					.clone()
+					.filter(|id| rule.matches(*id))
+					.collect::<Vec<_>>(),
+				"{rule}"
+			);
+		}
+		let last = invalid_ids(
+			18_446_744_000_000_000_000..=u64::MAX,
+			&Rule::Palindrome,
+			Radix::DECIMAL,
+		)
+		.last();
+		assert_eq!(last, Some(18_446_744_066_044_764_481));
+		assert_eq!(
+			count_invalid_ids(
+				[10_000_000_000..=99_999_999_999],
+				&Rule::Palindrome,
+				Radix::DECIMAL
+			),
+			900_000
+		);
+	}
+
+	#[test]
+	fn count_and_sum_invalid_ids_up_to_should_handle_u64_max() {
+		let (count, _) =
+			count_and_sum_invalid_ids_up_to(u64::MAX, &Rule::exactly(2), Radix::DECIMAL).unwrap();
+		assert_eq!(count, 1_844_674_407);
+		assert_eq!(
+			count_and_sum_invalid_ids_up_to(u64::MAX, &Rule::Palindrome, Radix::DECIMAL),
+			None
+		);
+	}
+}
@@ -0,0 +1,333 @@
+use itertools::Itertools;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::year2025::Radix;
+use crate::{Error, Result, error};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Rule {
+	Repetition {
+		min_repeats: u32,
+		max_repeats: u32,
+		min_pattern_size: u32,
+	},
+	Palindrome,
+	All(Vec<Rule>),
+	Any(Vec<Rule>),
+}
+
+impl Rule {
+	pub fn exactly(repeats: u32) -> Self {
+		Rule::Repetition {
+			min_repeats: repeats,
+			max_repeats: repeats,
+			min_pattern_size: 1,
+		}
+	}
+
+	pub fn at_least(repeats: u32) -> Self {
+		Rule::Repetition {
+			min_repeats: repeats,
+			max_repeats: u32::MAX,
+			min_pattern_size: 1,
+		}
+	}
+
+	pub fn min_pattern_size(size: u32) -> Self {
+		Rule::Repetition {
+			min_repeats: 2,
+			max_repeats: u32::MAX,
+			min_pattern_size: size,
+		}
+	}
+
+	pub fn matches(&self, id: u64) -> bool {
+		self.matches_in_radix(id, Radix::DECIMAL)
+	}
+
+	pub fn matches_in_radix(&self, id: u64, radix: Radix) -> bool {
+		let mut buffer = [0; u64::BITS as usize];
+		self.matches_digits(radix.digits(id, &mut buffer))
+	}
+
+	fn matches_digits(&self, digits: &[u8]) -> bool {
+		match self {
+			Rule::Repetition { .. } => self
+				.pattern_sizes(digits.len() as u32)
+				.unwrap_or_default()
+				.into_iter()
+				.any(|size| has_repeated_pattern(digits, size as usize)),
+			Rule::Palindrome => digits.iter().eq(digits.iter().rev()),
+			Rule::All(rules) => rules.iter().all(|rule| rule.matches_digits(digits)),
+			Rule::Any(rules) => rules.iter().any(|rule| rule.matches_digits(digits)),
+		}
+	}
+
+	pub fn explain(&self, id: u64, radix: Radix) -> Vec<Match<'_>> {
+		let mut buffer = [0; u64::BITS as usize];
+		self.explain_digits(radix.digits(id, &mut buffer), radix)
+	}
+
+	fn explain_digits(&self, digits: &[u8], radix: Radix) -> Vec<Match<'_>> {
+		match self {
+			Rule::Repetition { .. } => self
+				.pattern_sizes(digits.len() as u32)
+				.unwrap_or_default()
+				.into_iter()
+				.find(|size| has_repeated_pattern(digits, *size as usize))
+				.map(|size| {
+					let pattern = digits[..size as usize].iter().fold(0, |pattern, digit| {
+						pattern * radix.get() as u64 + *digit as u64
+					});
+					vec![Match {
+						rule: self,
+						pattern: Some((pattern, digits.len() as u32 / size)),
+					}]
+				})
+				.unwrap_or_default(),
+			Rule::Palindrome if self.matches_digits(digits) => vec![Match {
+				rule: self,
+				pattern: None,
+			}],
+			Rule::Palindrome => Vec::new(),
+			Rule::All(rules) => {
+				let matches: Vec<_> = rules
+					.iter()
+					.map(|rule| rule.explain_digits(digits, radix))
+					.collect();
+				if matches.iter().all(|matches| !matches.is_empty()) {
+					matches.into_iter().flatten().collect()
+				} else {
+					Vec::new()
+				}
+			}
+			Rule::Any(rules) => rules
+				.iter()
+				.flat_map(|rule| rule.explain_digits(digits, radix))
+				.collect(),
+		}
+	}
+
+	pub(super) fn pattern_sizes(&self, nb_digits: u32) -> Option<Vec<u32>> {
+		match self {
+			Rule::Repetition {
+				min_repeats,
+				max_repeats,
+				min_pattern_size,
+			} => Some(
+				(*min_pattern_size..nb_digits)
+					.filter(|size| {
+						nb_digits.is_multiple_of(*size)
+							&& (*min_repeats..=*max_repeats).contains(&(nb_digits / size))
+					})
+					.collect(),
+			),
+			Rule::Any(rules) => {
+				let mut sizes = Vec::new();
+				for rule in rules {
+					sizes.extend(rule.pattern_sizes(nb_digits)?);
+				}
+				sizes.sort_unstable();
+				sizes.dedup();
+				Some(sizes)
+			}
+			Rule::Palindrome | Rule::All(_) => None,
+		}
+	}
+}
+
+impl Display for Rule {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Rule::Repetition {
+				min_repeats,
+				max_repeats,
+				min_pattern_size: 1,
+			} if min_repeats == max_repeats => write!(f, "exactly:{min_repeats}"),
+			Rule::Repetition {
+				min_repeats,
+				max_repeats: u32::MAX,
+				min_pattern_size: 1,
+			} => write!(f, "at-least:{min_repeats}"),
+			Rule::Repetition {
+				min_repeats: 2,
+				max_repeats: u32::MAX,
+				min_pattern_size,
+			} => write!(f, "min-pattern:{min_pattern_size}"),
+			Rule::Repetition {
+				min_repeats,
+				max_repeats,
+				min_pattern_size,
+			} => write!(
+				f,
+				"{min_repeats} to {max_repeats} repeats of {min_pattern_size}+ digits"
+			),
+			Rule::Palindrome => write!(f, "palindrome"),
+			Rule::All(rules) => write!(f, "{}", rules.iter().join("+")),
+			Rule::Any(rules) => write!(f, "{}", rules.iter().join("|")),
+		}
+	}
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Match<'a> {
+	pub rule: &'a Rule,
+	pub pattern: Option<(u64, u32)>,
+}
+
+impl FromStr for Rule {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let mut alternatives = s
+			.split('|')
+			.map(|alternative| {
+				let mut rules = alternative
+					.split('+')
+					.map(parse_rule)
+					.collect::<Result<Vec<_>>>()?;
+				Ok(if rules.len() == 1 {
+					rules.remove(0)
+				} else {
+					Rule::All(rules)
+				})
+			})
+			.collect::<Result<Vec<_>>>()?;
+		Ok(if alternatives.len() == 1 {
+			alternatives.remove(0)
+		} else {
+			Rule::Any(alternatives)
+		})
+	}
+}
+
+fn parse_rule(rule: &str) -> Result<Rule> {
+	let rule = rule.trim();
+	let (name, value) = match rule.split_once(':') {
+		Some((name, value)) => (name, Some(value.parse::<u32>()?)),
+		None => (rule, None),
+	};
+	match (name, value) {
+		("part1", None) => Ok(Rule::exactly(2)),
+		("part2", None) => Ok(Rule::at_least(2)),
+		("palindrome", None) => Ok(Rule::Palindrome),
+		("exactly", Some(repeats)) if repeats >= 2 => Ok(Rule::exactly(repeats)),
+		("at-least", Some(repeats)) if repeats >= 2 => Ok(Rule::at_least(repeats)),
+		("min-pattern", Some(size)) if size >= 1 => Ok(Rule::min_pattern_size(size)),
+		_ => Err(error!("Invalid rule: {rule}")),
+	}
+}
+
+fn has_repeated_pattern(digits: &[u8], size: usize) -> bool {
+	if !digits.len().is_multiple_of(size) {
+		return false;
+	}
+	for i in 0..size {
+		for j in 1..(digits.len() / size) {
+			if digits[i] != digits[i + j * size] {
+				return false;
+			}
+		}
+	}
+	true
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn parse_rule_spec_should_combine_rules() {
+		assert_eq!(
+			"exactly:3+min-pattern:2|palindrome"
+				.parse::<Rule>()
+				.unwrap(),
+			Rule::Any(vec![
+				Rule::All(vec![Rule::exactly(3), Rule::min_pattern_size(2)]),
+				Rule::Palindrome,
+			])
+		);
+	}
+
+	#[test]
+	fn parse_rule_spec_should_reject_single_repeat() {
+		assert!("exactly:1".parse::<Rule>().is_err());
+	}
+
+	#[test]
+	fn exactly_should_match_pattern_repeated_that_many_times() {
+		let rule = Rule::exactly(3);
+		assert!(rule.matches(121212));
+		assert!(rule.matches(111));
+		assert!(!rule.matches(1212));
+	}
+
+	#[test]
+	fn at_least_should_match_pattern_repeated_more_times() {
+		let rule = Rule::at_least(3);
+		assert!(rule.matches(12121212));
+		assert!(!rule.matches(123123));
+	}
+
+	#[test]
+	fn min_pattern_size_should_ignore_shorter_patterns() {
+		let rule = Rule::min_pattern_size(2);
+		assert!(rule.matches(1212));
+		assert!(rule.matches(1111));
+		assert!(!rule.matches(111));
+	}
+
+	#[test]
+	fn palindrome_should_match_reversible_ids() {
+		assert!(Rule::Palindrome.matches(12321));
+		assert!(!Rule::Palindrome.matches(1232));
+	}
+
+	#[test]
+	fn all_should_require_every_rule() {
+		let rule = Rule::All(vec![Rule::at_least(2), Rule::Palindrome]);
+		assert!(rule.matches(1221_1221));
+		assert!(!rule.matches(1212));
+	}
+
+	#[test]
+	fn matches_in_radix_should_use_digits_of_that_radix() {
+		assert!(Rule::exactly(2).matches_in_radix(0b1010, Radix::new(2).unwrap()));
+		assert!(Rule::exactly(2).matches_in_radix(0xabab, Radix::new(16).unwrap()));
+		assert!(!Rule::exactly(2).matches_in_radix(0xabab, Radix::new(10).unwrap()));
+		assert!(Rule::Palindrome.matches_in_radix(0o757, Radix::new(8).unwrap()));
+	}
+
+	#[test]
+	fn explain_should_report_smallest_pattern_and_leaf_rules() {
+		let rule: Rule = "part2|palindrome".parse().unwrap();
+		assert_eq!(
+			rule.explain(123123, Radix::DECIMAL),
+			vec![Match {
+				rule: &Rule::at_least(2),
+				pattern: Some((123, 2)),
+			}]
+		);
+		assert_eq!(
+			rule.explain(1111, Radix::DECIMAL),
+			vec![
+				Match {
+					rule: &Rule::at_least(2),
+					pattern: Some((1, 4)),
+				},
+				Match {
+					rule: &Rule::Palindrome,
+					pattern: None,
+				},
+			]
+		);
+		assert!(rule.explain(1234, Radix::DECIMAL).is_empty());
+	}
+
+	#[test]
+	fn display_should_format_rule_spec() {
+		let spec = "exactly:3+min-pattern:2|palindrome|at-least:4";
+		assert_eq!(spec.parse::<Rule>().unwrap().to_string(), spec);
+	}
+}
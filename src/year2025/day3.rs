@@ -0,0 +1,837 @@
+use itertools::Itertools;
+use std::cell::OnceCell;
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+use crate::input::{ParseExt, ReadLines};
+use crate::year2025::Radix;
+use crate::{Error, Result, Solution, error};
+
+pub struct Day3<'a> {
+	input: &'a str,
+	banks: OnceCell<Vec<Bank>>,
+}
+
+impl<'a> Day3<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			banks: OnceCell::new(),
+		}
+	}
+
+	fn banks(&self) -> Result<&Vec<Bank>> {
+		self.banks
+			.get_or_try_init(|| parse_banks(self.input.as_bytes()))
+	}
+}
+
+impl Solution for Day3<'_> {
+	fn part_one(&self) -> Result<String> {
+		let sum = sum_of_largest_joltages(self.banks()?, 2)?;
+		Ok(format!("Sum of largest joltages: {sum}"))
+	}
+
+	fn part_two(&self) -> Result<String> {
+		let sum = sum_of_largest_joltages(self.banks()?, 12)?;
+		Ok(format!(
+			"Sum of largest joltages with safety override: {sum}"
+		))
+	}
+
+	fn stream_part_one(&self, input: &mut dyn Read) -> Result<String> {
+		let sum = stream_sum_of_largest_joltages(input, 2)?;
+		Ok(format!("Sum of largest joltages: {sum}"))
+	}
+
+	fn stream_part_two(&self, input: &mut dyn Read) -> Result<String> {
+		let sum = stream_sum_of_largest_joltages(input, 12)?;
+		Ok(format!(
+			"Sum of largest joltages with safety override: {sum}"
+		))
+	}
+
+	fn trace(&self, output: &mut dyn Write) -> Result<()> {
+		let highlight = if env::var_os("NO_COLOR").is_some() {
+			Highlight::Brackets
+		} else {
+			Highlight::Ansi
+		};
+		for nb_batteries in [2, 12] {
+			writeln!(output, "{nb_batteries} batteries:")?;
+			for bank in self.banks()? {
+				let selection = bank.select_largest(nb_batteries)?;
+				let bank = HighlightedBank {
+					bank,
+					batteries: &selection.batteries,
+					highlight,
+				};
+				writeln!(output, "{bank} -> {}", selection.joltage)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+fn sum_of_largest_joltages(banks: &[Bank], nb_batteries: usize) -> Result<u128> {
+	banks.iter().try_fold(0, |sum, bank| {
+		add_joltage(sum, bank.largest_joltage(nb_batteries)?)
+	})
+}
+
+fn stream_sum_of_largest_joltages(input: impl Read, nb_batteries: usize) -> Result<u128> {
+	input.try_read_lines().try_fold(0, |sum, line| {
+		add_joltage(sum, line?.parse::<Bank>()?.largest_joltage(nb_batteries)?)
+	})
+}
+
+fn add_joltage(sum: u128, joltage: u128) -> Result<u128> {
+	sum.checked_add(joltage)
+		.ok_or_else(|| error!("Sum of joltages overflows"))
+}
+
+#[derive(Debug)]
+pub struct Bank {
+	batteries: Vec<u64>,
+	radix: Radix,
+}
+
+impl Bank {
+	pub fn parse_with_radix(s: &str, radix: Radix) -> Result<Self> {
+		let batteries = s
+			.chars()
+			.map(|c| {
+				c.to_digit(radix.get())
+					.map(|d| d as u64)
+					.ok_or_else(|| error!("Invalid digit: {c}"))
+			})
+			.collect::<Result<_>>()?;
+		Ok(Self { batteries, radix })
+	}
+
+	pub fn largest_joltage(&self, nb_batteries: usize) -> Result<u128> {
+		Ok(self.select_largest(nb_batteries)?.joltage)
+	}
+
+	pub fn select_largest(&self, nb_batteries: usize) -> Result<Selection> {
+		self.select_among(0..self.batteries.len(), nb_batteries, |last, battery| {
+			last < battery
+		})
+	}
+
+	pub fn select_smallest(&self, nb_batteries: usize) -> Result<Selection> {
+		self.select_among(0..self.batteries.len(), nb_batteries, |last, battery| {
+			last > battery
+		})
+	}
+
+	pub fn select_largest_with(
+		&self,
+		nb_batteries: usize,
+		constraints: &Constraints,
+	) -> Result<Selection> {
+		let candidates: Vec<_> = (0..self.batteries.len())
+			.filter(|i| !constraints.forbidden_digits.contains(&self.batteries[*i]))
+			.collect();
+		match constraints.max_gap {
+			Some(max_gap) => self.select_largest_with_max_gap(&candidates, nb_batteries, max_gap),
+			None => self.select_among(candidates.into_iter(), nb_batteries, |last, battery| {
+				last < battery
+			}),
+		}
+	}
+
+	fn select_among(
+		&self,
+		candidates: impl ExactSizeIterator<Item = usize>,
+		nb_batteries: usize,
+		replaces: impl Fn(u64, u64) -> bool,
+	) -> Result<Selection> {
+		let mut nb_skipped = self.nb_skipped(candidates.len(), nb_batteries)?;
+		let mut batteries: Vec<usize> = Vec::with_capacity(candidates.len());
+		for i in candidates {
+			while nb_skipped > 0
+				&& batteries
+					.last()
+					.is_some_and(|last| replaces(self.batteries[*last], self.batteries[i]))
+			{
+				batteries.pop();
+				nb_skipped -= 1;
+			}
+			batteries.push(i);
+		}
+		batteries.truncate(nb_batteries);
+		Ok(Selection {
+			joltage: self.joltage(&batteries)?,
+			batteries,
+		})
+	}
+
+	fn select_largest_with_max_gap(
+		&self,
+		candidates: &[usize],
+		nb_batteries: usize,
+		max_gap: usize,
+	) -> Result<Selection> {
+		self.nb_skipped(candidates.len(), nb_batteries)?;
+		if nb_batteries == 0 {
+			return Ok(Selection {
+				batteries: Vec::new(),
+				joltage: 0,
+			});
+		}
+		let radix = self.radix.get() as u128;
+		let overflow = || error!("Joltage of {nb_batteries} batteries overflows");
+
+		// best[c][n] is the largest joltage of n + 1 batteries starting with candidate c, and the
+		// candidate chosen after it
+		let mut best: Vec<Vec<Option<(u128, usize)>>> =
+			vec![vec![None; nb_batteries]; candidates.len()];
+		for c in (0..candidates.len()).rev() {
+			let battery = self.batteries[candidates[c]] as u128;
+			best[c][0] = Some((battery, c));
+			let mut unit = Some(1_u128);
+			for n in 1..nb_batteries {
+				unit = unit.and_then(|unit| unit.checked_mul(radix));
+				best[c][n] = match (c + 1..candidates.len())
+					.take_while(|next| candidates[*next] - candidates[c] <= max_gap)
+					.filter_map(|next| best[next][n - 1].map(|(joltage, _)| (joltage, next)))
+					.max_by_key(|(joltage, next)| (*joltage, std::cmp::Reverse(*next)))
+				{
+					Some((joltage, next)) => {
+						let joltage = unit
+							.and_then(|unit| battery.checked_mul(unit))
+							.and_then(|first| first.checked_add(joltage))
+							.ok_or_else(overflow)?;
+						Some((joltage, next))
+					}
+					None => None,
+				};
+			}
+		}
+
+		let (mut c, _) = (0..candidates.len())
+			.filter_map(|c| best[c][nb_batteries - 1].map(|(joltage, _)| (c, joltage)))
+			.max_by_key(|(c, joltage)| (*joltage, std::cmp::Reverse(*c)))
+			.ok_or_else(|| {
+				error!("Cannot select {nb_batteries} batteries with gaps of at most {max_gap}")
+			})?;
+		let mut batteries = Vec::with_capacity(nb_batteries);
+		for n in (0..nb_batteries).rev() {
+			batteries.push(candidates[c]);
+			if let Some((_, next)) = best[c][n] {
+				c = next;
+			}
+		}
+		Ok(Selection {
+			joltage: self.joltage(&batteries)?,
+			batteries,
+		})
+	}
+
+	pub fn nth_largest_joltage(&self, nb_batteries: usize, n: u128) -> Result<Option<u128>> {
+		self.nb_skipped(self.batteries.len(), nb_batteries)?;
+		let len = self.batteries.len();
+
+		// next[i][d] is the position of the first battery with digit d at or after position i
+		let mut next = vec![vec![None; self.radix.get() as usize]; len + 1];
+		for i in (0..len).rev() {
+			next[i] = next[i + 1].clone();
+			next[i][self.batteries[i] as usize] = Some(i);
+		}
+		// nb_distinct[i][k] is the number of distinct joltages of k batteries from position i
+		let mut nb_distinct = vec![vec![0_u128; nb_batteries + 1]; len + 1];
+		for i in (0..=len).rev() {
+			nb_distinct[i][0] = 1;
+			for k in 1..=nb_batteries {
+				nb_distinct[i][k] = next[i].iter().flatten().fold(0, |count: u128, j| {
+					count.saturating_add(nb_distinct[j + 1][k - 1])
+				});
+			}
+		}
+		if n == 0 || n > nb_distinct[0][nb_batteries] {
+			return Ok(None);
+		}
+
+		let (mut n, mut i) = (n, 0);
+		let mut batteries = Vec::with_capacity(nb_batteries);
+		for k in (1..=nb_batteries).rev() {
+			for j in next[i].iter().rev().flatten() {
+				let count = nb_distinct[j + 1][k - 1];
+				if n <= count {
+					batteries.push(*j);
+					i = j + 1;
+					break;
+				}
+				n -= count;
+			}
+		}
+		self.joltage(&batteries).map(Some)
+	}
+
+	// Removing the first battery lower than the next one turns the largest joltage of k batteries
+	// into the largest joltage of k - 1 batteries. Joltages are returned as digits since most of
+	// them overflow for long banks.
+	pub fn largest_joltages(&self) -> Vec<Vec<u64>> {
+		let len = self.batteries.len();
+		let mut joltages = vec![Vec::new(); len];
+		let mut next: Vec<usize> = (1..=len).collect();
+		let mut previous: Vec<Option<usize>> = (0..len).map(|i| i.checked_sub(1)).collect();
+		let (mut first, mut cursor) = (0, 0);
+		for nb_batteries in (1..=len).rev() {
+			let digits = &mut joltages[nb_batteries - 1];
+			let mut battery = first;
+			while battery < len {
+				digits.push(self.batteries[battery]);
+				battery = next[battery];
+			}
+
+			let mut removed = cursor;
+			while next[removed] < len && self.batteries[removed] >= self.batteries[next[removed]] {
+				removed = next[removed];
+			}
+			match previous[removed] {
+				Some(battery) => next[battery] = next[removed],
+				None => first = next[removed],
+			}
+			if next[removed] < len {
+				previous[next[removed]] = previous[removed];
+			}
+			cursor = previous[removed].unwrap_or(first);
+		}
+		joltages
+	}
+
+	#[cfg(test)]
+	fn largest_joltage_by_scan(&self, nb_batteries: usize) -> Result<u128> {
+		self.joltage(&self.largest_batteries_by_scan(nb_batteries)?)
+	}
+
+	#[cfg(test)]
+	fn largest_batteries_by_scan(&self, nb_batteries: usize) -> Result<Vec<usize>> {
+		self.nb_skipped(self.batteries.len(), nb_batteries)?;
+		let mut batteries = Vec::new();
+		let mut start = 0;
+		while batteries.len() < nb_batteries {
+			let mut battery = start;
+			for i in (start + 1)..=(self.batteries.len() + batteries.len() - nb_batteries) {
+				if self.batteries[i] > self.batteries[battery] {
+					battery = i;
+				}
+			}
+			batteries.push(battery);
+			start = battery + 1;
+		}
+		Ok(batteries)
+	}
+
+	fn nb_skipped(&self, nb_available: usize, nb_batteries: usize) -> Result<usize> {
+		nb_available.checked_sub(nb_batteries).ok_or_else(|| {
+			error!("Cannot select {nb_batteries} batteries from {nb_available} available")
+		})
+	}
+
+	fn joltage(&self, batteries: &[usize]) -> Result<u128> {
+		batteries.iter().try_fold(0_u128, |joltage, battery| {
+			joltage
+				.checked_mul(self.radix.get() as u128)
+				.and_then(|joltage| joltage.checked_add(self.batteries[*battery] as u128))
+				.ok_or_else(|| error!("Joltage of {} batteries overflows", batteries.len()))
+		})
+	}
+}
+
+pub struct JoltageTable {
+	pub radix: Radix,
+	pub joltages: Vec<Vec<Vec<u64>>>,
+	pub totals: Vec<Vec<u64>>,
+}
+
+impl JoltageTable {
+	pub fn new(banks: &[Bank]) -> Result<Self> {
+		let radix = banks.first().map_or(Radix::DECIMAL, |bank| bank.radix);
+		let mut totals: Vec<Vec<u64>> = Vec::new();
+		let joltages = banks
+			.iter()
+			.map(|bank| {
+				if bank.radix != radix {
+					return Err(error!("Banks use different radixes"));
+				}
+				let joltages = bank.largest_joltages();
+				if totals.len() < joltages.len() {
+					totals.resize(joltages.len(), Vec::new());
+				}
+				for (total, joltage) in totals.iter_mut().zip(&joltages) {
+					*total = add_digits(total, joltage, radix.get() as u64);
+				}
+				Ok(joltages)
+			})
+			.collect::<Result<_>>()?;
+		Ok(Self {
+			radix,
+			joltages,
+			totals,
+		})
+	}
+
+	pub fn write_csv(&self, output: &mut dyn Write) -> Result<()> {
+		let header = (1..=self.totals.len()).map(|nb_batteries| nb_batteries.to_string());
+		write_csv_row(output, "bank", header)?;
+		for (i, joltages) in self.joltages.iter().enumerate() {
+			let cells = (0..self.totals.len())
+				.map(|column| self.format_cell(joltages.get(column).map_or(&[], Vec::as_slice)));
+			write_csv_row(output, &(i + 1).to_string(), cells)?;
+		}
+		let totals = self.totals.iter().map(|total| self.format_cell(total));
+		write_csv_row(output, "total", totals)
+	}
+
+	fn format_cell(&self, digits: &[u64]) -> String {
+		digits
+			.iter()
+			.map(|digit| char::from_digit(*digit as u32, self.radix.get()).unwrap_or('?'))
+			.collect()
+	}
+}
+
+fn add_digits(first: &[u64], second: &[u64], radix: u64) -> Vec<u64> {
+	let mut carry = 0;
+	let mut sum: Vec<_> = first
+		.iter()
+		.rev()
+		.copied()
+		.zip_longest(second.iter().rev().copied())
+		.map(|digits| {
+			let digit = digits.reduce(|first, second| first + second) + carry;
+			carry = digit / radix;
+			digit % radix
+		})
+		.collect();
+	if carry > 0 {
+		sum.push(carry);
+	}
+	sum.reverse();
+	sum
+}
+
+fn write_csv_row(
+	output: &mut dyn Write,
+	label: &str,
+	cells: impl Iterator<Item = String>,
+) -> Result<()> {
+	write!(output, "{label}")?;
+	for cell in cells {
+		write!(output, ",{cell}")?;
+	}
+	writeln!(output)?;
+	Ok(())
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Constraints {
+	pub max_gap: Option<usize>,
+	pub forbidden_digits: Vec<u64>,
+}
+
+#[derive(Debug)]
+pub struct Selection {
+	pub batteries: Vec<usize>,
+	pub joltage: u128,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Highlight {
+	Ansi,
+	Brackets,
+}
+
+pub struct HighlightedBank<'a> {
+	pub bank: &'a Bank,
+	pub batteries: &'a [usize],
+	pub highlight: Highlight,
+}
+
+impl Display for HighlightedBank<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let mut batteries = self.batteries.iter().peekable();
+		for (i, battery) in self.bank.batteries.iter().enumerate() {
+			let battery = char::from_digit(*battery as u32, self.bank.radix.get()).unwrap_or('?');
+			if batteries.next_if_eq(&&i).is_none() {
+				write!(f, "{battery}")?;
+			} else if self.highlight == Highlight::Ansi {
+				write!(f, "\x1b[1;32m{battery}\x1b[0m")?;
+			} else {
+				write!(f, "[{battery}]")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for Bank {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		Self::parse_with_radix(s, Radix::DECIMAL)
+	}
+}
+
+pub fn parse_banks(input: &[u8]) -> Result<Vec<Bank>> {
+	input.read_lines().parse().collect()
+}
+
+pub fn parse_banks_with_radix(input: &[u8], radix: Radix) -> Result<Vec<Bank>> {
+	input
+		.read_lines()
+		.map(|line| Bank::parse_with_radix(&line, radix))
+		.collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::test_util::Lcg;
+
+	const EXAMPLE: &[u8] = b"\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+	#[test]
+	fn bank_largest_joltage_should_return_98_for_987654321111111_and_2_batteries() {
+		let bank: Bank = "987654321111111".parse().unwrap();
+		assert_eq!(bank.largest_joltage(2).unwrap(), 98);
+	}
+
+	#[test]
+	fn bank_largest_joltage_should_return_89_for_811111111111119_and_2_batteries() {
+		let bank: Bank = "811111111111119".parse().unwrap();
+		assert_eq!(bank.largest_joltage(2).unwrap(), 89);
+	}
+
+	#[test]
+	fn bank_largest_joltage_should_return_92_for_818181911112111_and_2_batteries() {
+		let bank: Bank = "818181911112111".parse().unwrap();
+		assert_eq!(bank.largest_joltage(2).unwrap(), 92);
+	}
+
+	#[test]
+	fn sum_of_largest_joltages_should_return_357_for_example_and_2_batteries() {
+		let example_banks = parse_banks(EXAMPLE).unwrap();
+		assert_eq!(sum_of_largest_joltages(&example_banks, 2).unwrap(), 357);
+	}
+
+	#[test]
+	fn bank_largest_joltage_should_return_987654321111_for_987654321111111_and_12_batteries() {
+		let bank: Bank = "987654321111111".parse().unwrap();
+		assert_eq!(bank.largest_joltage(12).unwrap(), 987654321111);
+	}
+
+	#[test]
+	fn sum_of_largest_joltages_should_return_3121910778619_for_example_and_12_batteries() {
+		let example_banks = parse_banks(EXAMPLE).unwrap();
+		assert_eq!(
+			sum_of_largest_joltages(&example_banks, 12).unwrap(),
+			3121910778619
+		);
+	}
+
+	#[test]
+	fn stream_sum_of_largest_joltages_should_return_357_for_example_and_2_batteries() {
+		assert_eq!(stream_sum_of_largest_joltages(EXAMPLE, 2).unwrap(), 357);
+	}
+
+	#[test]
+	fn stream_sum_of_largest_joltages_should_fail_on_invalid_digit() {
+		assert!(stream_sum_of_largest_joltages(&b"123\n1x3\n"[..], 2).is_err());
+	}
+
+	#[test]
+	fn bank_largest_joltage_should_match_scan() {
+		let mut lcg = Lcg::new(42);
+		for length in 1..=40 {
+			let digits: String = (0..length)
+				.map(|_| char::from(b'1' + (lcg.next_u64() >> 60) as u8 % 9))
+				.collect();
+			let bank: Bank = digits.parse().unwrap();
+			for nb_batteries in 1..=length.min(38) {
+				assert_eq!(
+					bank.largest_joltage(nb_batteries).unwrap(),
+					bank.largest_joltage_by_scan(nb_batteries).unwrap(),
+					"{digits} {nb_batteries}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn bank_largest_joltage_should_handle_long_banks() {
+		let digits = "123456789".repeat(50_000);
+		let bank: Bank = digits.parse().unwrap();
+		assert_eq!(bank.largest_joltage(12).unwrap(), 999999999999);
+	}
+
+	#[test]
+	fn bank_largest_joltage_should_handle_more_than_19_batteries() {
+		let bank: Bank = "9".repeat(38).parse().unwrap();
+		assert_eq!(bank.largest_joltage(38).unwrap(), 10_u128.pow(38) - 1);
+	}
+
+	#[test]
+	fn bank_largest_joltage_should_fail_on_overflow() {
+		let bank: Bank = "9".repeat(40).parse().unwrap();
+		assert!(bank.largest_joltage(39).is_err());
+	}
+
+	#[test]
+	fn bank_largest_joltage_should_fail_when_bank_is_too_small() {
+		let bank: Bank = "987".parse().unwrap();
+		assert_eq!(
+			bank.largest_joltage(4).unwrap_err().0,
+			"Cannot select 4 batteries from 3 available"
+		);
+		assert!(bank.largest_joltage_by_scan(4).is_err());
+	}
+
+	#[test]
+	fn bank_select_largest_should_return_chosen_batteries() {
+		let bank: Bank = "818181911112111".parse().unwrap();
+		let selection = bank.select_largest(2).unwrap();
+		assert_eq!(selection.batteries, vec![6, 11]);
+		assert_eq!(selection.joltage, 92);
+	}
+
+	#[test]
+	fn highlighted_bank_should_surround_chosen_batteries() {
+		let bank: Bank = "818181911112111".parse().unwrap();
+		let selection = bank.select_largest(2).unwrap();
+		let highlighted = HighlightedBank {
+			bank: &bank,
+			batteries: &selection.batteries,
+			highlight: Highlight::Brackets,
+		};
+		assert_eq!(highlighted.to_string(), "818181[9]1111[2]111");
+	}
+
+	fn random_banks() -> impl Iterator<Item = Bank> {
+		let mut lcg = Lcg::new(7);
+		(1..=9).flat_map(move |length| {
+			(0..20)
+				.map(|_| (0..length).map(|_| (lcg.next_u64() >> 60) % 4).collect())
+				.map(|batteries| Bank {
+					batteries,
+					radix: Radix::DECIMAL,
+				})
+				.collect::<Vec<_>>()
+		})
+	}
+
+	fn brute_force_joltages(
+		bank: &Bank,
+		nb_batteries: usize,
+		constraints: &Constraints,
+	) -> Vec<u128> {
+		(0..bank.batteries.len())
+			.combinations(nb_batteries)
+			.filter(|batteries| {
+				batteries.iter().all(|battery| {
+					!constraints
+						.forbidden_digits
+						.contains(&bank.batteries[*battery])
+				}) && batteries.windows(2).all(|pair| {
+					constraints
+						.max_gap
+						.is_none_or(|max_gap| pair[1] - pair[0] <= max_gap)
+				})
+			})
+			.map(|batteries| bank.joltage(&batteries).unwrap())
+			.sorted()
+			.collect()
+	}
+
+	#[test]
+	fn bank_select_smallest_should_match_brute_force() {
+		for bank in random_banks() {
+			for nb_batteries in 1..=bank.batteries.len() {
+				let joltages = brute_force_joltages(&bank, nb_batteries, &Constraints::default());
+				let selection = bank.select_smallest(nb_batteries).unwrap();
+				assert_eq!(Some(&selection.joltage), joltages.first());
+				assert_eq!(
+					bank.joltage(&selection.batteries).unwrap(),
+					selection.joltage
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn bank_nth_largest_joltage_should_match_brute_force() {
+		for bank in random_banks() {
+			for nb_batteries in 1..=bank.batteries.len() {
+				let mut joltages =
+					brute_force_joltages(&bank, nb_batteries, &Constraints::default());
+				joltages.dedup();
+				joltages.reverse();
+				for n in 1..=joltages.len() + 1 {
+					assert_eq!(
+						bank.nth_largest_joltage(nb_batteries, n as u128).unwrap(),
+						joltages.get(n - 1).copied()
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn bank_select_largest_with_constraints_should_match_brute_force() {
+		for bank in random_banks() {
+			for nb_batteries in 1..=bank.batteries.len() {
+				for constraints in [
+					Constraints {
+						max_gap: Some(1),
+						forbidden_digits: Vec::new(),
+					},
+					Constraints {
+						max_gap: Some(2),
+						forbidden_digits: vec![3],
+					},
+					Constraints {
+						max_gap: None,
+						forbidden_digits: vec![2],
+					},
+				] {
+					let joltages = brute_force_joltages(&bank, nb_batteries, &constraints);
+					let selection = bank.select_largest_with(nb_batteries, &constraints);
+					match joltages.last() {
+						Some(joltage) => {
+							let selection = selection.unwrap();
+							assert_eq!(selection.joltage, *joltage);
+							assert_eq!(bank.joltage(&selection.batteries).unwrap(), *joltage);
+							assert!(selection.batteries.iter().all(|i| {
+								!constraints.forbidden_digits.contains(&bank.batteries[*i])
+							}));
+							assert!(selection.batteries.windows(2).all(|pair| {
+								constraints
+									.max_gap
+									.is_none_or(|max_gap| pair[1] - pair[0] <= max_gap)
+							}));
+						}
+						None => assert!(selection.is_err()),
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn bank_largest_joltages_should_match_largest_joltage_for_every_nb_batteries() {
+		for bank in random_banks().chain(parse_banks(EXAMPLE).unwrap()) {
+			let expected: Vec<_> = (1..=bank.batteries.len())
+				.map(|nb_batteries| {
+					let batteries = bank.largest_batteries_by_scan(nb_batteries).unwrap();
+					batteries
+						.iter()
+						.map(|i| bank.batteries[*i])
+						.collect::<Vec<_>>()
+				})
+				.collect();
+			assert_eq!(bank.largest_joltages(), expected);
+		}
+	}
+
+	#[test]
+	fn joltage_table_should_report_every_nb_batteries_of_long_banks() {
+		let mut lcg = Lcg::new(100);
+		let digits: String = (0..100)
+			.map(|_| char::from(b'1' + (lcg.next_u64() >> 60) as u8 % 9))
+			.collect();
+		let banks = parse_banks(format!("{digits}\n{digits}\n").as_bytes()).unwrap();
+		let table = JoltageTable::new(&banks).unwrap();
+		for nb_batteries in 1..=100 {
+			let batteries = banks[0].largest_batteries_by_scan(nb_batteries).unwrap();
+			let joltage: Vec<_> = batteries.iter().map(|i| banks[0].batteries[*i]).collect();
+			assert_eq!(table.joltages[0][nb_batteries - 1], joltage);
+			assert_eq!(
+				table.totals[nb_batteries - 1],
+				add_digits(&joltage, &joltage, 10)
+			);
+		}
+		assert_eq!(table.format_cell(&table.joltages[1][99]), digits);
+	}
+
+	#[test]
+	fn add_digits_should_carry() {
+		assert_eq!(add_digits(&[9, 9], &[1], 10), vec![1, 0, 0]);
+		assert_eq!(add_digits(&[], &[0xf, 3], 16), vec![0xf, 3]);
+		assert_eq!(add_digits(&[1, 1], &[1], 2), vec![1, 0, 0]);
+	}
+
+	#[test]
+	fn joltage_table_should_export_csv_with_totals() {
+		let banks = parse_banks(
+			b"819
+27
+",
+		)
+		.unwrap();
+		let mut csv = Vec::new();
+		JoltageTable::new(&banks)
+			.unwrap()
+			.write_csv(&mut csv)
+			.unwrap();
+		assert_eq!(
+			String::from_utf8(csv).unwrap(),
+			"bank,1,2,3\n1,9,89,819\n2,7,27,\ntotal,16,116,819\n"
+		);
+	}
+
+	#[test]
+	fn parse_banks_with_radix_should_read_hexadecimal_batteries() {
+		let banks = parse_banks_with_radix(b"1aF3\n", Radix::new(16).unwrap()).unwrap();
+		let selection = banks[0].select_largest(2).unwrap();
+		assert_eq!(selection.batteries, vec![2, 3]);
+		assert_eq!(selection.joltage, 0xf3);
+		assert_eq!(
+			banks[0].largest_joltages(),
+			vec![
+				vec![0xf],
+				vec![0xf, 3],
+				vec![0xa, 0xf, 3],
+				vec![1, 0xa, 0xf, 3]
+			]
+		);
+		let highlighted = HighlightedBank {
+			bank: &banks[0],
+			batteries: &selection.batteries,
+			highlight: Highlight::Brackets,
+		};
+		assert_eq!(highlighted.to_string(), "1a[f][3]");
+	}
+
+	#[test]
+	fn bank_selections_should_work_in_binary() {
+		let bank = Bank::parse_with_radix("1001101", Radix::new(2).unwrap()).unwrap();
+		assert_eq!(bank.largest_joltage(3).unwrap(), 0b111);
+		assert_eq!(bank.largest_joltage_by_scan(3).unwrap(), 0b111);
+		assert_eq!(bank.select_smallest(3).unwrap().joltage, 0b000);
+		assert_eq!(bank.nth_largest_joltage(3, 2).unwrap(), Some(0b110));
+		assert!(Bank::parse_with_radix("102", Radix::new(2).unwrap()).is_err());
+	}
+
+	#[test]
+	fn bank_select_largest_with_max_gap_should_fail_on_overflow() {
+		let bank = Bank::parse_with_radix(&"2".repeat(81), Radix::new(3).unwrap()).unwrap();
+		let constraints = Constraints {
+			max_gap: Some(1),
+			forbidden_digits: Vec::new(),
+		};
+		assert_eq!(
+			bank.select_largest_with(81, &constraints).unwrap_err().0,
+			"Joltage of 81 batteries overflows"
+		);
+	}
+}
@@ -0,0 +1,328 @@
+use std::cell::OnceCell;
+use std::str::FromStr;
+
+use crate::{Error, Result, Solution, error};
+
+pub struct Day4<'a> {
+	input: &'a str,
+	warehouse: OnceCell<Warehouse>,
+}
+
+impl<'a> Day4<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			warehouse: OnceCell::new(),
+		}
+	}
+
+	fn warehouse(&self) -> Result<&Warehouse> {
+		self.warehouse.get_or_try_init(|| self.input.parse())
+	}
+}
+
+impl Solution for Day4<'_> {
+	fn part_one(&self) -> Result<String> {
+		let nb_accessible_rolls = self.warehouse()?.count_accessible_rolls();
+		Ok(format!("Number of accessible rolls: {nb_accessible_rolls}"))
+	}
+
+	fn part_two(&self) -> Result<String> {
+		let (_, removed) = remove_all_rolls(self.warehouse()?);
+		Ok(format!("Number of removed rolls: {removed}"))
+	}
+}
+
+fn remove_all_rolls(warehouse: &Warehouse) -> (Warehouse, usize) {
+	let mut current = warehouse.clone();
+	let mut nb_adjacent_rolls: Vec<_> = (0..current.rolls.len())
+		.map(|i| {
+			current
+				.neighbours(i)
+				.filter(|neighbour| current.rolls[*neighbour])
+				.count()
+		})
+		.collect();
+	let mut accessible: Vec<_> = (0..current.rolls.len())
+		.filter(|i| current.rolls[*i] && nb_adjacent_rolls[*i] < 4)
+		.collect();
+
+	let mut total_removed = 0;
+	while let Some(i) = accessible.pop() {
+		current.rolls[i] = false;
+		total_removed += 1;
+		for neighbour in current.neighbours(i) {
+			if current.rolls[neighbour] {
+				nb_adjacent_rolls[neighbour] -= 1;
+				if nb_adjacent_rolls[neighbour] == 3 {
+					accessible.push(neighbour);
+				}
+			}
+		}
+	}
+	(current, total_removed)
+}
+
+#[cfg(test)]
+fn remove_all_rolls_by_rounds(warehouse: &Warehouse) -> (Warehouse, usize) {
+	let mut current = warehouse.clone();
+	let mut total_removed = 0;
+	loop {
+		let (new_warehouse, removed) = current.remove_rolls();
+		if removed == 0 {
+			break;
+		}
+		current = new_warehouse;
+		total_removed += removed;
+	}
+	(current, total_removed)
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Warehouse {
+	width: usize,
+	height: usize,
+	rolls: Vec<bool>,
+}
+
+impl Warehouse {
+	fn has_roll(&self, column: usize, row: usize) -> bool {
+		self.rolls[row * self.width + column]
+	}
+
+	fn neighbours(&self, i: usize) -> impl Iterator<Item = usize> {
+		let (column, row) = (i % self.width, i / self.width);
+		(row.saturating_sub(1)..=(row + 1).min(self.height - 1))
+			.flat_map(move |r| {
+				(column.saturating_sub(1)..=(column + 1).min(self.width - 1))
+					.map(move |c| r * self.width + c)
+			})
+			.filter(move |neighbour| *neighbour != i)
+	}
+
+	fn count_adjacent_rolls(&self, column: usize, row: usize) -> usize {
+		let mut rolls = 0;
+		for r in row.saturating_sub(1)..=(row + 1).min(self.height - 1) {
+			for c in column.saturating_sub(1)..=(column + 1).min(self.width - 1) {
+				if (c, r) != (column, row) && self.has_roll(c, r) {
+					rolls += 1;
+				}
+			}
+		}
+		rolls
+	}
+
+	fn count_accessible_rolls(&self) -> usize {
+		let mut accessible_rows = 0;
+		for r in 0..self.height {
+			for c in 0..self.width {
+				if self.has_roll(c, r) && self.count_adjacent_rolls(c, r) < 4 {
+					accessible_rows += 1;
+				}
+			}
+		}
+		accessible_rows
+	}
+
+	#[cfg(test)]
+	fn remove_rolls(&self) -> (Self, usize) {
+		let mut rolls = vec![false; self.rolls.len()];
+		let mut removed = 0;
+		for r in 0..self.height {
+			for c in 0..self.width {
+				if self.has_roll(c, r) {
+					if self.count_adjacent_rolls(c, r) >= 4 {
+						rolls[r * self.width + c] = true;
+					} else {
+						removed += 1;
+					}
+				}
+			}
+		}
+		(Self { rolls, ..*self }, removed)
+	}
+}
+
+impl FromStr for Warehouse {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let lines: Vec<_> = s.lines().collect();
+		let width = lines.first().map_or(0, |line| line.len());
+		if width == 0 {
+			return Err(error!("Empty warehouse"));
+		}
+		let mut rolls = Vec::with_capacity(width * lines.len());
+		for (row, line) in lines.iter().enumerate() {
+			if line.len() != width {
+				return Err(error!(
+					"Line {} has {} columns instead of {width}",
+					row + 1,
+					line.len()
+				));
+			}
+			for c in line.chars() {
+				match c {
+					'@' => rolls.push(true),
+					'.' => rolls.push(false),
+					_ => return Err(error!("Invalid character on line {}: {c}", row + 1)),
+				}
+			}
+		}
+		Ok(Self {
+			width,
+			height: lines.len(),
+			rolls,
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::test_util::Lcg;
+
+	const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+	#[test]
+	fn parse_example() {
+		let warehouse: Warehouse = EXAMPLE.parse().unwrap();
+		assert_eq!((warehouse.width, warehouse.height), (10, 10));
+		assert_eq!(
+			warehouse.rolls,
+			[
+				[
+					false, false, true, true, false, true, true, true, true, false
+				],
+				[
+					true, true, true, false, true, false, true, false, true, true
+				],
+				[true, true, true, true, true, false, true, false, true, true],
+				[
+					true, false, true, true, true, true, false, false, true, false
+				],
+				[true, true, false, true, true, true, true, false, true, true],
+				[false, true, true, true, true, true, true, true, false, true],
+				[
+					false, true, false, true, false, true, false, true, true, true
+				],
+				[true, false, true, true, true, false, true, true, true, true],
+				[false, true, true, true, true, true, true, true, true, false],
+				[
+					true, false, true, false, true, true, true, false, true, false
+				],
+			]
+			.concat()
+		);
+	}
+
+	#[test]
+	fn count_adjacent_rolls_should_return_2_for_example_0_0() {
+		let warehouse: Warehouse = EXAMPLE.parse().unwrap();
+		assert_eq!(warehouse.count_adjacent_rolls(0, 0), 2);
+	}
+
+	#[test]
+	fn count_accessible_rolls_should_return_13_for_example() {
+		let warehouse: Warehouse = EXAMPLE.parse().unwrap();
+		assert_eq!(warehouse.count_accessible_rolls(), 13);
+	}
+
+	#[test]
+	fn remove_rolls_example() {
+		let warehouse: Warehouse = EXAMPLE.parse().unwrap();
+		let (new_warehouse, removed) = warehouse.remove_rolls();
+		assert_eq!(removed, 13);
+		assert_eq!(
+			new_warehouse,
+			"\
+.......@..
+.@@.@.@.@@
+@@@@@...@@
+@.@@@@..@.
+.@.@@@@.@.
+.@@@@@@@.@
+.@.@.@.@@@
+..@@@.@@@@
+.@@@@@@@@.
+....@@@...
+"
+			.parse::<Warehouse>()
+			.unwrap()
+		);
+	}
+
+	#[test]
+	fn remove_all_rolls_example() {
+		let warehouse: Warehouse = EXAMPLE.parse().unwrap();
+		let (new_warehouse, removed) = remove_all_rolls(&warehouse);
+		assert_eq!(removed, 43);
+		assert_eq!(
+			new_warehouse,
+			"\
+..........
+..........
+..........
+....@@....
+...@@@@...
+...@@@@@..
+...@.@.@@.
+...@@.@@@.
+...@@@@@..
+....@@@...
+"
+			.parse::<Warehouse>()
+			.unwrap()
+		);
+	}
+
+	#[test]
+	fn parse_rectangular_warehouse() {
+		let warehouse: Warehouse = "@.@@\n.@@.\n".parse().unwrap();
+		assert_eq!((warehouse.width, warehouse.height), (4, 2));
+		assert_eq!(warehouse.count_adjacent_rolls(1, 1), 3);
+		assert_eq!(warehouse.count_accessible_rolls(), 5);
+	}
+
+	#[test]
+	fn parse_should_reject_ragged_lines() {
+		let error = "@.@\n.@\n".parse::<Warehouse>().unwrap_err();
+		assert_eq!(error.0, "Line 2 has 2 columns instead of 3");
+	}
+
+	#[test]
+	fn parse_should_reject_unknown_characters() {
+		let error = "@.@\n.x.\n".parse::<Warehouse>().unwrap_err();
+		assert_eq!(error.0, "Invalid character on line 2: x");
+	}
+
+	#[test]
+	fn remove_all_rolls_should_match_removal_by_rounds() {
+		let mut lcg = Lcg::new(3);
+		for (width, height) in [(1, 1), (7, 3), (20, 20), (45, 12)] {
+			let rolls = (0..width * height)
+				.map(|_| (lcg.next_u64() >> 61) != 0)
+				.collect();
+			let warehouse = Warehouse {
+				width,
+				height,
+				rolls,
+			};
+			assert_eq!(
+				remove_all_rolls(&warehouse),
+				remove_all_rolls_by_rounds(&warehouse)
+			);
+		}
+	}
+}
@@ -1,28 +1,56 @@
+use itertools::process_results;
 use std::cell::OnceCell;
 use std::cmp::Ordering;
+use std::io::Read;
 use std::str::FromStr;
 
 use crate::input::{ParseExt, ReadLines};
 use crate::{Error, Result, Solution, error};
 
-const FRESH_INGREDIENTS: &[u8] = include_bytes!("../input/day5_fresh_ingredients");
-const AVAILABLE_INGREDIENTS: &[u8] = include_bytes!("../input/day5_available_ingredients");
+pub struct Day5<'a> {
+	input: &'a str,
+	fresh_ingredients: OnceCell<Vec<FreshIngredients>>,
+}
 
-#[derive(Default)]
-pub struct Day5(OnceCell<Vec<FreshIngredients>>);
+impl<'a> Day5<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			fresh_ingredients: OnceCell::new(),
+		}
+	}
 
-impl Day5 {
 	fn fresh_ingredients(&self) -> Result<&Vec<FreshIngredients>> {
-		self.0
-			.get_or_try_init(|| parse_fresh_ingredients(FRESH_INGREDIENTS))
+		self.fresh_ingredients.get_or_try_init(|| {
+			let (fresh_ingredients, _) = split_input(self.input)?;
+			parse_fresh_ingredients(fresh_ingredients.as_bytes())
+		})
 	}
 }
 
-impl Solution for Day5 {
+impl Solution for Day5<'_> {
 	fn part_one(&self) -> Result<String> {
-		let available_ingredients = parse_available_ingredients(AVAILABLE_INGREDIENTS)?;
+		let (_, available_ingredients) = split_input(self.input)?;
+		let available_ingredients = parse_available_ingredients(available_ingredients.as_bytes())?;
+		let available_fresh_ingredients =
+			count_available_fresh_ingredients(self.fresh_ingredients()?, available_ingredients);
+		Ok(format!(
+			"Number of available fresh ingredients: {available_fresh_ingredients}"
+		))
+	}
+
+	fn stream_part_one(&self, input: &mut dyn Read) -> Result<String> {
+		let mut lines = input.try_read_lines();
+		let fresh_ingredients = lines
+			.by_ref()
+			.take_while(|line| !matches!(line, Ok(line) if line.is_empty()))
+			.map(|line| line?.parse())
+			.collect::<Result<Vec<_>>>()?;
+		let available_ingredients = lines.map(|line| -> Result<u64> { Ok(line?.parse()?) });
 		let available_fresh_ingredients =
-			count_available_fresh_ingredients(self.fresh_ingredients()?, &available_ingredients);
+			process_results(available_ingredients, |available_ingredients| {
+				count_available_fresh_ingredients(&fresh_ingredients, available_ingredients)
+			})?;
 		Ok(format!(
 			"Number of available fresh ingredients: {available_fresh_ingredients}"
 		))
@@ -36,14 +64,14 @@ impl Solution for Day5 {
 
 fn count_available_fresh_ingredients(
 	fresh_ingredients: &[FreshIngredients],
-	available_ingredients: &[u64],
+	available_ingredients: impl IntoIterator<Item = u64>,
 ) -> usize {
 	available_ingredients
-		.iter()
+		.into_iter()
 		.filter(|ingredient| {
 			fresh_ingredients
 				.iter()
-				.any(|fresh_ingredients| fresh_ingredients.contains(**ingredient))
+				.any(|fresh_ingredients| fresh_ingredients.contains(*ingredient))
 		})
 		.count()
 }
@@ -73,6 +101,12 @@ fn count_fresh_ids(fresh_ingredients: &[FreshIngredients]) -> u64 {
 		.sum()
 }
 
+fn split_input(input: &str) -> Result<(&str, &str)> {
+	input
+		.split_once("\n\n")
+		.ok_or_else(|| error!("Missing blank line between fresh and available ingredients"))
+}
+
 fn parse_fresh_ingredients(input: &[u8]) -> Result<Vec<FreshIngredients>> {
 	input.read_lines().parse().collect::<Result<_>>()
 }
@@ -148,6 +182,20 @@ mod test {
 ";
 
 	const EXAMPLE_AVAILABLE_INGREDIENTS: &[u8] = b"\
+1
+5
+8
+11
+17
+32
+";
+
+	const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
 1
 5
 8
@@ -183,11 +231,33 @@ mod test {
 		let available_ingredients =
 			parse_available_ingredients(EXAMPLE_AVAILABLE_INGREDIENTS).unwrap();
 
-		let result = count_available_fresh_ingredients(&fresh_ingredients, &available_ingredients);
+		let result = count_available_fresh_ingredients(&fresh_ingredients, available_ingredients);
 
 		assert_eq!(result, 3);
 	}
 
+	#[test]
+	fn split_input_should_separate_fresh_and_available_ingredients() {
+		let (fresh_ingredients, available_ingredients) = split_input(EXAMPLE).unwrap();
+		assert_eq!(
+			fresh_ingredients.as_bytes(),
+			EXAMPLE_FRESH_INGREDIENTS.trim_ascii_end()
+		);
+		assert_eq!(
+			available_ingredients.as_bytes(),
+			EXAMPLE_AVAILABLE_INGREDIENTS
+		);
+	}
+
+	#[test]
+	fn stream_part_one_should_count_available_fresh_ingredients_of_reader() {
+		let result = Day5::new("")
+			.stream_part_one(&mut EXAMPLE.as_bytes())
+			.unwrap();
+
+		assert_eq!(result, "Number of available fresh ingredients: 3");
+	}
+
 	#[test]
 	fn count_fresh_ids_should_return_14_for_example() {
 		let fresh_ingredients = parse_fresh_ingredients(EXAMPLE_FRESH_INGREDIENTS).unwrap();
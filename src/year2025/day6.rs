@@ -5,19 +5,25 @@ use std::sync::LazyLock;
 use crate::input::ReadLines;
 use crate::{Error, Result, Solution, error};
 
-const INPUT: &[u8] = include_bytes!("../input/day6");
+pub struct Day6<'a> {
+	input: &'a str,
+}
 
-pub struct Day6;
+impl<'a> Day6<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self { input }
+	}
+}
 
-impl Solution for Day6 {
+impl Solution for Day6<'_> {
 	fn part_one(&self) -> Result<String> {
-		let problems = parse_problems_part1(INPUT)?;
+		let problems = parse_problems_part1(self.input.as_bytes())?;
 		let sum_of_all_answers = sum_of_all_problem_answers(&problems);
 		Ok(format!("Sum of all answers: {sum_of_all_answers}"))
 	}
 
 	fn part_two(&self) -> Result<String> {
-		let problems = parse_problems_part2(INPUT)?;
+		let problems = parse_problems_part2(self.input.as_bytes())?;
 		let sum_of_all_answers = sum_of_all_problem_answers(&problems);
 		Ok(format!("Sum of all answers: {sum_of_all_answers}"))
 	}
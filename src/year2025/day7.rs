@@ -4,18 +4,25 @@ use std::str::FromStr;
 
 use crate::{Error, Result, Solution};
 
-const INPUT: &str = include_str!("../input/day7");
+pub struct Day7<'a> {
+	input: &'a str,
+	diagram: OnceCell<Diagram<141, 142>>,
+}
 
-#[derive(Default)]
-pub struct Day7(OnceCell<Diagram<141, 142>>);
+impl<'a> Day7<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			diagram: OnceCell::new(),
+		}
+	}
 
-impl Day7 {
 	fn diagram(&self) -> Result<&Diagram<141, 142>> {
-		self.0.get_or_try_init(|| INPUT.parse())
+		self.diagram.get_or_try_init(|| self.input.parse())
 	}
 }
 
-impl Solution for Day7 {
+impl Solution for Day7<'_> {
 	fn part_one(&self) -> Result<String> {
 		let splits = self.diagram()?.count_splits();
 		Ok(format!("Number of splits: {splits}"))
@@ -6,21 +6,28 @@ use std::str::FromStr;
 use crate::input::{ParseExt, ReadLines};
 use crate::{Error, Result, Solution, error};
 
-const INPUT: &[u8] = include_bytes!("../input/day8");
+pub struct Day8<'a> {
+	input: &'a str,
+	arcs: OnceCell<BinaryHeap<Arc>>,
+}
 
-#[derive(Default)]
-pub struct Day8(OnceCell<BinaryHeap<Arc>>);
+impl<'a> Day8<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			arcs: OnceCell::new(),
+		}
+	}
 
-impl Day8 {
 	fn arcs(&self) -> Result<&BinaryHeap<Arc>> {
-		self.0.get_or_try_init(|| {
-			let positions = parse_positions(INPUT)?;
+		self.arcs.get_or_try_init(|| {
+			let positions = parse_positions(self.input.as_bytes())?;
 			Ok(compute_arcs(&positions))
 		})
 	}
 }
 
-impl Solution for Day8 {
+impl Solution for Day8<'_> {
 	fn part_one(&self) -> Result<String> {
 		let (a, b, c) = connect_junction_boxes(self.arcs()?.clone(), 1000)?;
 		Ok(format!(
@@ -6,18 +6,26 @@ use crate::error::error;
 use crate::input::{ParseExt, ReadLines};
 use crate::{Error, Result, Solution};
 
-pub const INPUT: &[u8] = include_bytes!("../input/day9");
+pub struct Day9<'a> {
+	input: &'a str,
+	tiles: OnceCell<Vec<Tile>>,
+}
 
-#[derive(Default)]
-pub struct Day9(OnceCell<Vec<Tile>>);
+impl<'a> Day9<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			tiles: OnceCell::new(),
+		}
+	}
 
-impl Day9 {
 	fn tiles(&self) -> Result<&Vec<Tile>> {
-		self.0.get_or_try_init(|| parse_tiles(INPUT))
+		self.tiles
+			.get_or_try_init(|| parse_tiles(self.input.as_bytes()))
 	}
 }
 
-impl Solution for Day9 {
+impl Solution for Day9<'_> {
 	fn part_one(&self) -> Result<String> {
 		let largest_rectangle_area = find_largest_rectangle_area(self.tiles()?);
 		Ok(format!("Largest rectangle area: {largest_rectangle_area}"))
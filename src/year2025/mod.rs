@@ -0,0 +1,31 @@
+pub mod day1;
+mod day10;
+mod day11;
+pub mod day2;
+pub mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+pub mod day9;
+mod radix;
+
+use crate::SolutionFactory;
+pub use radix::Radix;
+
+pub fn solutions() -> Vec<SolutionFactory> {
+	vec![
+		|input| Box::new(day1::Day1::new(input)),
+		|input| Box::new(day2::Day2::new(input)),
+		|input| Box::new(day3::Day3::new(input)),
+		|input| Box::new(day4::Day4::new(input)),
+		|input| Box::new(day5::Day5::new(input)),
+		|input| Box::new(day6::Day6::new(input)),
+		|input| Box::new(day7::Day7::new(input)),
+		|input| Box::new(day8::Day8::new(input)),
+		|input| Box::new(day9::Day9::new(input)),
+		|input| Box::new(day10::Day10::new(input)),
+		|input| Box::new(day11::Day11::new(input)),
+	]
+}
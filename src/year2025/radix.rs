@@ -0,0 +1,65 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Result, error};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Radix(u32);
+
+impl Radix {
+	pub const DECIMAL: Radix = Radix(10);
+
+	pub fn new(radix: u32) -> Result<Self> {
+		if (2..=36).contains(&radix) {
+			Ok(Self(radix))
+		} else {
+			Err(error!("Invalid radix: {radix}"))
+		}
+	}
+
+	pub fn get(self) -> u32 {
+		self.0
+	}
+
+	pub(super) fn digits(self, mut id: u64, buffer: &mut [u8; u64::BITS as usize]) -> &[u8] {
+		let radix = self.0 as u64;
+		let mut start = buffer.len();
+		loop {
+			start -= 1;
+			buffer[start] = (id % radix) as u8;
+			id /= radix;
+			if id == 0 {
+				return &buffer[start..];
+			}
+		}
+	}
+
+	pub(super) fn format(self, id: u64) -> String {
+		let mut buffer = [0; u64::BITS as usize];
+		self.digits(id, &mut buffer)
+			.iter()
+			.map(|digit| char::from_digit(*digit as u32, self.0).unwrap_or('?'))
+			.collect()
+	}
+}
+
+impl Display for Radix {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn new_should_reject_radixes_without_digits() {
+		for radix in [0, 1, 37] {
+			assert_eq!(
+				Radix::new(radix).unwrap_err().0,
+				format!("Invalid radix: {radix}")
+			);
+		}
+		assert_eq!(Radix::new(36).unwrap().format(35), "z");
+	}
+}
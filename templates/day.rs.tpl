@@ -0,0 +1,51 @@
+use std::cell::OnceCell;
+
+use crate::input::ReadLines;
+use crate::{Result, Solution, error};
+
+pub struct Day{{day}}<'a> {
+	input: &'a str,
+	lines: OnceCell<Vec<String>>,
+}
+
+impl<'a> Day{{day}}<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self {
+			input,
+			lines: OnceCell::new(),
+		}
+	}
+
+	fn lines(&self) -> Result<&Vec<String>> {
+		self.lines
+			.get_or_try_init(|| parse_lines(self.input.as_bytes()))
+	}
+}
+
+impl Solution for Day{{day}}<'_> {
+	fn part_one(&self) -> Result<String> {
+		let nb_lines = self.lines()?.len();
+		Ok(format!("Number of lines: {nb_lines}"))
+	}
+
+	fn part_two(&self) -> Result<String> {
+		Err(error!("Part 2 is not solved yet"))
+	}
+}
+
+fn parse_lines(input: &[u8]) -> Result<Vec<String>> {
+	Ok(input.read_lines().collect())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const EXAMPLE: &[u8] = b"\
+";
+
+	#[test]
+	fn parse_lines_example() {
+		assert_eq!(parse_lines(EXAMPLE).unwrap(), Vec::<String>::new());
+	}
+}